@@ -0,0 +1,6 @@
+use win32console::console::WinConsole;
+
+fn main(){
+    // Prints every combination of foreground and background colors
+    WinConsole::output().print_color_test().expect("Cannot print the color grid");
+}
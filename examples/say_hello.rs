@@ -1,13 +1,5 @@
 use win32console::console::WinConsole;
 use win32console::structs::console_color::ConsoleColor;
-use win32console::input::*;
-
-// Virtual key codes
-// https://docs.microsoft.com/en-us/windows/win32/inputdev/virtual-key-codes
-const ESCAPE : u16 = 0x1B;
-const BACKSPACE: u16 = 0x08;
-const ENTER : u16 = 0x0D;
-const SPACE : u16 = 0x20;
 
 fn main() {
     // Clears the screen
@@ -21,64 +13,17 @@ fn main() {
 
     // Writes to the screen a message depending if 'name' is blank or not
     if name.is_not_blank(){
-        write_color_string(format!("\nHello {}!", name), ConsoleColor::DarkBlue);
+        write_color_string(format!("Hello {}!", name), ConsoleColor::DarkBlue);
     }
     else{
-        write_color_str("\nWait, what?", ConsoleColor::DarkBlue);
+        write_color_str("Wait, what?", ConsoleColor::DarkBlue);
     }
 }
 
 fn read_string() -> String{
-    // The buffer to store the string to write
-    let mut buffer: String = String::new();
-
-    loop{
-        // Get the current input event
-        if let KeyEvent(key) = WinConsole::input().read_single_input().unwrap(){
-            // Only check for key down events
-            if key.key_down{
-                let uchar = key.u_char;
-                // Write only if is alphanumeric or punctuation
-                if uchar.is_ascii_alphanumeric() || uchar.is_ascii_punctuation(){
-                    // Writes the char to the screen and push it to the string buffer
-                    write_char(uchar);
-                    buffer.push(uchar);
-                }
-                else{
-                    match key.virtual_key_code {
-                        ESCAPE => { break; },   // Exit on [ESC] press
-                        ENTER => { break; }     // Exit on [Enter] press
-                        SPACE => {
-                            // Write the whitespace to the screen and string buffer
-                            WinConsole::output().write_utf8(" ".as_bytes()).unwrap();
-                            buffer.push(' ');
-                        },
-                        BACKSPACE => {
-                            // If the buffer is not empty removes the last char from the screen
-                            // and string buffer.
-                            if !buffer.is_empty(){
-                                WinConsole::output().write_utf8(b"\x08 \x08").unwrap();
-                                buffer.pop();
-                            }
-                        },
-                        _ => {}
-                    }
-                }
-            }
-        }
-    }
-
-    // String ends with a newline
-    buffer.trim().to_string()
-}
-
-fn write_char(char_value: char){
-    // The buffer to store the char
-    let mut buffer = [0u8; 1];
-    // Store the encode value to teh buffer
-    char_value.encode_utf8(&mut buffer);
-    // Write the utf8 buffer to the screen
-    WinConsole::output().write_utf8(&buffer).unwrap();
+    // Reads the line in raw mode, echoing the typed characters
+    let line = WinConsole::input().read_line_edited().unwrap();
+    line.trim().to_string()
 }
 
 fn write_color_str(value: &str, color: ConsoleColor){
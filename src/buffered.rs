@@ -0,0 +1,215 @@
+use std::io::{Result, Write};
+use crate::console::WinConsole;
+
+/// Wraps a [`WinConsole`] and accumulates the written bytes in an internal buffer,
+/// the bytes are written to the console handle when [`flush`] is called,
+/// when the buffer exceeds its capacity or when a newline is written if is line buffered.
+///
+/// An incomplete `UTF-8` character at the end of the buffer is kept
+/// until the rest of its bytes are written.
+///
+/// Any remaining data is written when the `BufferedConsole` is dropped,
+/// errors on drop are ignored.
+///
+/// # Example
+/// ```
+/// use std::io::Write;
+/// use win32console::console::WinConsole;
+/// use win32console::buffered::BufferedConsole;
+///
+/// let mut console = BufferedConsole::new(WinConsole::output());
+/// for i in 0..100{
+///     write!(console, "{} ", i).unwrap();
+/// }
+///
+/// // Writes all the numbers at once
+/// console.flush().unwrap();
+/// ```
+///
+/// [`WinConsole`]: ../console/struct.WinConsole.html
+/// [`flush`]: #method.flush
+#[derive(Debug)]
+pub struct BufferedConsole{
+    // The console where the data is written.
+    console: WinConsole,
+    // The bytes pending to write.
+    buffer: Vec<u8>,
+    // The maximum number of bytes to hold before write to the console.
+    capacity: usize,
+    // Whether flush when a newline is written.
+    line_buffered: bool
+}
+
+impl BufferedConsole{
+    /// The default capacity of the buffer in bytes.
+    pub const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+    /// Creates a new `BufferedConsole` with the default capacity.
+    #[inline]
+    pub fn new(console: WinConsole) -> Self{
+        BufferedConsole::with_capacity(BufferedConsole::DEFAULT_CAPACITY, console)
+    }
+
+    /// Creates a new `BufferedConsole` with the specified capacity in bytes.
+    pub fn with_capacity(capacity: usize, console: WinConsole) -> Self{
+        BufferedConsole{
+            console,
+            buffer: Vec::with_capacity(capacity),
+            capacity,
+            line_buffered: false
+        }
+    }
+
+    /// Sets whether the buffer is written to the console each time a newline is written.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Write;
+    /// use win32console::console::WinConsole;
+    /// use win32console::buffered::BufferedConsole;
+    ///
+    /// let mut console = BufferedConsole::new(WinConsole::output()).line_buffered(true);
+    /// writeln!(console, "Hello World!").unwrap();
+    /// assert!(console.get_buffer().is_empty());
+    /// ```
+    #[inline]
+    pub fn line_buffered(mut self, enable: bool) -> BufferedConsole{
+        self.line_buffered = enable;
+        self
+    }
+
+    /// Gets the console where the data is written.
+    #[inline]
+    pub fn get_console(&self) -> &WinConsole{
+        &self.console
+    }
+
+    /// Gets the bytes that are not written yet to the console.
+    #[inline]
+    pub fn get_buffer(&self) -> &[u8]{
+        &self.buffer
+    }
+
+    /// Gets the capacity of the buffer in bytes.
+    #[inline]
+    pub fn get_capacity(&self) -> usize{
+        self.capacity
+    }
+
+    /// Checks if this console is flushed each time a newline is written.
+    #[inline]
+    pub fn is_line_buffered(&self) -> bool{
+        self.line_buffered
+    }
+
+    /// Writes the pending bytes to the console, except an incomplete character at the end.
+    fn flush_buffer(&mut self) -> Result<()>{
+        let len = complete_len(&self.buffer);
+        if len > 0{
+            self.console.write_all_utf8(&self.buffer[..len])?;
+            self.buffer.drain(..len);
+        }
+
+        Ok(())
+    }
+}
+
+// Gets the number of bytes before an incomplete `UTF-8` character at the end of the data,
+// invalid data is not truncated so the error is reported when is written.
+fn complete_len(data: &[u8]) -> usize{
+    match std::str::from_utf8(data){
+        Err(e) if e.error_len().is_none() => e.valid_up_to(),
+        _ => data.len()
+    }
+}
+
+impl Write for BufferedConsole{
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        if self.buffer.len() + buf.len() > self.capacity{
+            self.flush_buffer()?;
+        }
+
+        // Too large to be buffered, write directly to the console,
+        // an incomplete character at the end is buffered in the next write
+        if self.buffer.is_empty() && buf.len() >= self.capacity{
+            let len = complete_len(buf);
+            if len > 0{
+                self.console.write_all_utf8(&buf[..len])?;
+                return Ok(len);
+            }
+        }
+
+        // The buffer may exceed the capacity while holds an incomplete character
+        self.buffer.extend_from_slice(buf);
+
+        if self.buffer.len() > self.capacity || (self.line_buffered && buf.contains(&b'\n')){
+            self.flush_buffer()?;
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.flush_buffer()?;
+        self.console.flush_output()
+    }
+}
+
+impl Drop for BufferedConsole{
+    fn drop(&mut self) {
+        let _ = self.flush_buffer();
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::test_utils::{lock_console, RedirectedFile};
+
+    #[test]
+    fn write_after_flush_test(){
+        let _lock = lock_console();
+        let mut console = BufferedConsole::new(WinConsole::output());
+        console.write_all(b"Hello World!").unwrap();
+        assert_eq!(b"Hello World!", console.get_buffer());
+
+        console.flush().unwrap();
+        assert!(console.get_buffer().is_empty());
+    }
+
+    #[test]
+    fn write_over_capacity_test(){
+        let _lock = lock_console();
+        let mut console = BufferedConsole::with_capacity(4, WinConsole::output());
+        console.write_all(b"abc").unwrap();
+        assert_eq!(b"abc", console.get_buffer());
+
+        console.write_all(b"de").unwrap();
+        assert_eq!(b"de", console.get_buffer());
+    }
+
+    #[test]
+    fn write_to_redirected_handle_test(){
+        let file = RedirectedFile::create("win32console_buffered_console.txt");
+        let mut console = BufferedConsole::with_capacity(4, file.console());
+
+        console.write_all(b"abc").unwrap();
+        assert!(file.contents().is_empty());
+
+        // A character split between writes is written once is complete
+        let data = "ñandú".as_bytes();
+        console.write_all(&data[..1]).unwrap();
+        console.write_all(&data[1..]).unwrap();
+        assert_eq!("abcñandú".as_bytes(), file.contents().as_slice());
+
+        // Too large to be buffered, only the incomplete character is kept
+        let data = "hello ñ".as_bytes();
+        console.write_all(&data[..7]).unwrap();
+        assert_eq!("abcñandúhello ".as_bytes(), file.contents().as_slice());
+        assert_eq!(&data[6..7], console.get_buffer());
+
+        console.write_all(&data[7..]).unwrap();
+        console.flush().unwrap();
+        assert_eq!("abcñandúhello ñ".as_bytes(), file.contents().as_slice());
+    }
+}
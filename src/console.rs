@@ -1,17 +1,16 @@
 use std::{
-    convert::TryFrom,
     io::{Error, ErrorKind, Result},
     iter,
     mem::{MaybeUninit},
     slice,
     str,
     ptr::null_mut,
+    time::Duration,
 };
 
 use winapi::{
     um::{
         consoleapi::{
-            WriteConsoleA,
             AllocConsole,
             GetConsoleCP,
             GetConsoleMode,
@@ -22,14 +21,18 @@ use winapi::{
             SetConsoleMode,
             WriteConsoleW
         },
-        fileapi::{CreateFileW, OPEN_EXISTING, ReadFile, WriteFile},
+        fileapi::{CreateFileW, FlushFileBuffers, GetFileType, OPEN_EXISTING, ReadFile, WriteFile},
         handleapi::INVALID_HANDLE_VALUE,
         processenv::{GetStdHandle, SetStdHandle},
-        winbase::{STD_ERROR_HANDLE, STD_INPUT_HANDLE, STD_OUTPUT_HANDLE},
+        winbase::{STD_ERROR_HANDLE, STD_INPUT_HANDLE, STD_OUTPUT_HANDLE, INFINITE, WAIT_OBJECT_0, FILE_TYPE_UNKNOWN},
+        synchapi::WaitForSingleObject,
         wincon::{
+            ATTACH_PARENT_PROCESS,
             CONSOLE_FONT_INFOEX,
+            CTRL_BREAK_EVENT,
             FillConsoleOutputAttribute,
             FillConsoleOutputCharacterW,
+            GenerateConsoleCtrlEvent,
             GetConsoleScreenBufferInfo,
             GetConsoleScreenBufferInfoEx,
             GetConsoleTitleW,
@@ -68,20 +71,24 @@ use winapi::{
             ScrollConsoleScreenBufferW
         },
         wincontypes::{PCHAR_INFO, PSMALL_RECT},
-        winnt::{FILE_SHARE_READ, FILE_SHARE_WRITE, GENERIC_READ, GENERIC_WRITE},
+        winnt::{FILE_SHARE_READ, FILE_SHARE_WRITE, GENERIC_READ, GENERIC_WRITE, OSVERSIONINFOW},
     },
     ctypes::c_void,
     shared::minwindef::MAX_PATH,
-    um::wincon::{GetConsoleProcessList, SetConsoleHistoryInfo, CONSOLE_HISTORY_INFO, GetConsoleHistoryInfo, GetConsoleCursorInfo, CONSOLE_CURSOR_INFO, GetConsoleDisplayMode, CONSOLE_FULLSCREEN_MODE, CONSOLE_WINDOWED_MODE, SetConsoleDisplayMode, COORD, CONSOLE_FULLSCREEN, CONSOLE_FULLSCREEN_HARDWARE, GetConsoleWindow, GetConsoleFontSize, ReadConsoleOutputCharacterW, ReadConsoleOutputAttribute, WriteConsoleInputA, WriteConsoleOutputAttribute, WriteConsoleOutputCharacterW},
+    shared::ntdef::NTSTATUS,
+    shared::winerror::{ERROR_ACCESS_DENIED, ERROR_INVALID_HANDLE, WAIT_TIMEOUT},
+    um::wincon::{GetConsoleProcessList, SetConsoleHistoryInfo, CONSOLE_HISTORY_INFO, GetConsoleHistoryInfo, GetConsoleCursorInfo, SetConsoleCursorInfo, CONSOLE_CURSOR_INFO, GetConsoleDisplayMode, CONSOLE_FULLSCREEN_MODE, CONSOLE_WINDOWED_MODE, SetConsoleDisplayMode, COORD, CONSOLE_FULLSCREEN, CONSOLE_FULLSCREEN_HARDWARE, GetConsoleWindow, GetConsoleFontSize, ReadConsoleOutputCharacterW, ReadConsoleOutputAttribute, WriteConsoleInputA, WriteConsoleInputW, WriteConsoleOutputAttribute, WriteConsoleOutputCharacterW},
     um::winnt::{HANDLE},
     shared::windef::RECT,
-    um::winuser::{MONITORINFO, GetMonitorInfoA, MonitorFromWindow, MONITOR_DEFAULTTOPRIMARY, GetWindowRect},
+    um::winuser::{MONITORINFO, GetMonitorInfoA, MonitorFromWindow, MONITOR_DEFAULTTOPRIMARY, GetWindowRect, SetWindowPos, HWND_TOPMOST, HWND_NOTOPMOST, SWP_NOSIZE, SWP_NOMOVE, SWP_NOZORDER, SWP_NOACTIVATE, MapVirtualKeyW, ToUnicode, MAPVK_VK_TO_VSC, VK_CAPITAL, VK_CONTROL, VK_LCONTROL, VK_LMENU, VK_MENU, VK_NUMLOCK, VK_RCONTROL, VK_RMENU, VK_SHIFT, VK_BACK, VK_RETURN},
     shared::windef::HWND__,
     um::utilapiset::Beep
 };
 
 use crate::{
+    error::ConsoleError,
     structs::char_info::CharInfo,
+    structs::code_page::CodePage,
     structs::console_color::ConsoleColor,
     structs::console_font_info::ConsoleFontInfo,
     structs::console_font_info_ex::ConsoleFontInfoEx,
@@ -90,6 +97,7 @@ use crate::{
     structs::console_screen_buffer_info_ex::ConsoleScreenBufferInfoEx,
     structs::coord::Coord,
     structs::handle::Handle,
+    structs::input_event::ControlKeyState,
     structs::input_record::InputRecord,
     structs::console_selection_info::ConsoleSelectionInfo,
     structs::small_rect::SmallRect,
@@ -97,6 +105,13 @@ use crate::{
     structs::console_cursor_info::ConsoleCursorInfo
 };
 
+#[link(name = "ntdll")]
+extern "system" {
+    // Not included in `winapi`, unlike `GetVersionExW` reports the actual version of Windows
+    // even if the application don't declare it as supported in its manifest.
+    fn RtlGetVersion(version_information: *mut OSVERSIONINFOW) -> NTSTATUS;
+}
+
 /// Provides an access to the windows console of the current process and provides methods for
 /// interact with it.
 ///
@@ -189,6 +204,113 @@ pub struct ConsoleOptions{
     share_mode: u32
 }
 
+/// The features supported by the console host, obtained by calling [`capabilities`].
+///
+/// [`capabilities`]: struct.WinConsole.html#method.capabilities
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ConsoleCapabilities{
+    /// Whether the output process the virtual terminal sequences,
+    /// see `ConsoleMode::ENABLE_VIRTUAL_TERMINAL_PROCESSING`.
+    pub virtual_terminal: bool,
+    /// Whether the output can display 24-bit colors using virtual terminal sequences,
+    /// which requires the Windows 10 build 14931 or later.
+    pub truecolor: bool,
+    /// Whether the input can receive mouse events, see `ConsoleMode::ENABLE_MOUSE_INPUT`.
+    pub mouse_input: bool,
+    /// Whether the output can display the grid attributes in any code page,
+    /// see `ConsoleMode::ENABLE_LVB_GRID_WORLDWIDE`.
+    pub lvb_grid: bool
+}
+
+/// Describes what a handle can be used for, obtained by calling [`describe_handle`].
+///
+/// [`describe_handle`]: struct.WinConsole.html#method.describe_handle
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct HandleInfo{
+    /// Whether the handle is an open handle, even if is not a console handle.
+    pub is_valid: bool,
+    /// Whether the handle is a console input or screen buffer handle.
+    pub is_console: bool,
+    /// Whether the handle can be used as a console input handle, as `WinConsole::input()`.
+    pub is_input: bool,
+    /// Whether the handle can be used as a console output handle, as `WinConsole::output()`.
+    pub is_output: bool
+}
+
+/// Contains a copy of the content of a console screen buffer and its cursor position,
+/// obtained by calling [`save_screen`].
+///
+/// [`save_screen`]: struct.WinConsole.html#method.save_screen
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SavedScreen{
+    // The characters and attributes of the screen buffer.
+    buffer: Vec<CharInfo>,
+    // The size of the screen buffer in rows and columns.
+    size: Coord,
+    // The position of the cursor when the screen was saved.
+    #[cfg_attr(feature = "serde", serde(default))]
+    cursor_position: Coord
+}
+
+/// An iterator over the new sizes of the console screen buffer, obtained by calling [`resize_events`].
+///
+/// The previous console mode is restored when the iterator is dropped.
+///
+/// [`resize_events`]: struct.WinConsole.html#method.resize_events
+#[derive(Debug)]
+pub struct ResizeEvents<'a>{
+    // The console where the input events are read.
+    console: &'a WinConsole,
+    // The console mode before enable the window input.
+    old_mode: u32
+}
+
+/// An iterator over the printable characters typed in the console, obtained by calling [`printable_chars`].
+///
+/// [`printable_chars`]: struct.WinConsole.html#method.printable_chars
+#[derive(Debug)]
+pub struct PrintableChars<'a>{
+    // The console where the input events are read.
+    console: &'a WinConsole,
+    // The character of the last key event and the number of times is still pending to yield.
+    pending: Option<(char, u16)>
+}
+
+/// Restores the title of the console when dropped, obtained by calling [`scoped_title`].
+///
+/// [`scoped_title`]: struct.WinConsole.html#method.scoped_title
+#[derive(Debug)]
+pub struct ScopedTitle{
+    // The title of the console before set the new title.
+    old_title: String
+}
+
+/// Restores the mode of a console when dropped, obtained by calling [`scoped_raw_mode`].
+///
+/// [`scoped_raw_mode`]: struct.WinConsole.html#method.scoped_raw_mode
+#[derive(Debug)]
+pub struct ModeGuard<'a>{
+    // The console which mode will be restored.
+    console: &'a WinConsole,
+    // The console mode before the guard was created.
+    old_mode: u32
+}
+
+// Restores the code pages of the console when dropped, even if a panic occurs.
+struct CodePageGuard{
+    // The input code page to restore, if was changed.
+    input_code_page: Option<u32>,
+    // The output code page to restore, if was changed.
+    output_code_page: Option<u32>
+}
+
+// Sets the screen buffer as the active screen buffer when dropped, even if a panic occurs.
+struct ActiveScreenBufferGuard{
+    // The screen buffer to restore, if was not restored yet.
+    handle: Option<Handle>
+}
+
 impl ConsoleMode {
     /// CTRL+C is processed by the system and is not placed in the input buffer.
     /// If the input buffer is being read by `ReadFile` or `ReadConsole`,
@@ -226,6 +348,31 @@ impl ConsoleMode {
     /// into Console Virtual Terminal Sequences that can be retrieved by a supporting application
     /// through `ReadFile` or `ReadConsole` functions.
     pub const ENABLE_VIRTUAL_TERMINAL_INPUT: u32 = 0x0200;
+
+    /// Characters written by the `WriteFile` or `WriteConsole` function or echoed by the `ReadFile` or `ReadConsole`
+    /// function are parsed for ASCII control sequences, and the correct action is performed.
+    /// This is an output mode, and should be used with an output handle.
+    pub const ENABLE_PROCESSED_OUTPUT: u32 = 0x0001;
+
+    /// When writing with `WriteFile` or `WriteConsole` or echoing with `ReadFile` or `ReadConsole`,
+    /// the cursor moves to the beginning of the next row when it reaches the end of the current row.
+    /// This is an output mode, and should be used with an output handle.
+    pub const ENABLE_WRAP_AT_EOL_OUTPUT: u32 = 0x0002;
+
+    /// When writing with `WriteFile` or `WriteConsole`, characters are parsed for VT100 and similar control
+    /// character sequences that control cursor movement, color/font mode, and other operations.
+    /// This is an output mode, and should be used with an output handle.
+    pub const ENABLE_VIRTUAL_TERMINAL_PROCESSING: u32 = 0x0004;
+
+    /// When writing with `WriteFile` or `WriteConsole`, this adds an additional state to end-of-line wrapping
+    /// that can delay the cursor move and buffer scroll operations.
+    /// This is an output mode, and should be used with an output handle.
+    pub const DISABLE_NEWLINE_AUTO_RETURN: u32 = 0x0008;
+
+    /// The APIs for writing character attributes including `WriteConsoleOutput` and `WriteConsoleOutputAttribute`
+    /// allow the usage of flags from character attributes to adjust the color of the foreground and background of text.
+    /// This is an output mode, and should be used with an output handle.
+    pub const ENABLE_LVB_GRID_WORLDWIDE: u32 = 0x0010;
 }
 
 impl ConsoleTextAttribute {
@@ -305,6 +452,147 @@ impl ConsoleOptions{
     }
 }
 
+impl SavedScreen{
+    /// Gets the characters and attributes of the saved screen buffer.
+    #[inline]
+    pub fn get_buffer(&self) -> &[CharInfo]{
+        &self.buffer
+    }
+
+    /// Gets the size of the saved screen buffer in rows and columns.
+    #[inline]
+    pub fn get_size(&self) -> Coord{
+        self.size
+    }
+
+    /// Gets the position of the cursor when the screen was saved.
+    #[inline]
+    pub fn get_cursor_position(&self) -> Coord{
+        self.cursor_position
+    }
+}
+
+impl Iterator for ResizeEvents<'_>{
+    type Item = Result<Coord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.console.read_single_input() {
+                Ok(InputRecord::WindowBufferSizeEvent(event)) => return Some(Ok(event.size)),
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+impl Iterator for PrintableChars<'_>{
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((c, count)) = self.pending {
+            self.pending = if count > 1 { Some((c, count - 1)) } else { None };
+            return Some(c);
+        }
+
+        loop {
+            match self.console.read_single_input() {
+                Ok(InputRecord::KeyEvent(event)) if event.key_down && !event.u_char.is_control() => {
+                    if event.repeat_count > 1 {
+                        self.pending = Some((event.u_char, event.repeat_count - 1));
+                    }
+
+                    return Some(event.u_char);
+                }
+                Ok(_) => {}
+                Err(_) => return None,
+            }
+        }
+    }
+}
+
+impl Drop for ResizeEvents<'_>{
+    fn drop(&mut self) {
+        if self.old_mode & ConsoleMode::ENABLE_WINDOW_INPUT == 0 {
+            let _ = self.console.set_mode(self.old_mode);
+        }
+    }
+}
+
+impl ScopedTitle{
+    /// Gets the title that will be restored.
+    #[inline]
+    pub fn get_old_title(&self) -> &str{
+        &self.old_title
+    }
+}
+
+impl Drop for ScopedTitle{
+    fn drop(&mut self) {
+        let _ = WinConsole::set_title(&self.old_title);
+    }
+}
+
+impl ModeGuard<'_>{
+    /// Gets the mode that will be restored.
+    #[inline]
+    pub fn get_old_mode(&self) -> u32{
+        self.old_mode
+    }
+}
+
+impl Drop for ModeGuard<'_>{
+    fn drop(&mut self) {
+        let _ = self.console.set_mode(self.old_mode);
+    }
+}
+
+impl CodePageGuard{
+    // Restores the code pages returning the first error found,
+    // any code page that could not be restored is restored again on drop.
+    fn restore(mut self) -> Result<()>{
+        if let Some(code_page) = self.input_code_page.take(){
+            WinConsole::set_input_code(code_page)?;
+        }
+
+        if let Some(code_page) = self.output_code_page.take(){
+            WinConsole::set_output_code(code_page)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for CodePageGuard{
+    fn drop(&mut self) {
+        if let Some(code_page) = self.input_code_page.take(){
+            let _ = WinConsole::set_input_code(code_page);
+        }
+
+        if let Some(code_page) = self.output_code_page.take(){
+            let _ = WinConsole::set_output_code(code_page);
+        }
+    }
+}
+
+impl ActiveScreenBufferGuard{
+    // Restores the active screen buffer returning the error if any.
+    fn restore(mut self) -> Result<()>{
+        match self.handle.take() {
+            Some(handle) => WinConsole::set_active_console_screen_buffer(&handle),
+            None => Ok(())
+        }
+    }
+}
+
+impl Drop for ActiveScreenBufferGuard{
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take(){
+            let _ = WinConsole::set_active_console_screen_buffer(&handle);
+        }
+    }
+}
+
 // Get console handle associative methods
 impl WinConsole {
     /// Gets the specified handle by type.
@@ -512,10 +800,59 @@ impl WinConsole {
     pub fn with_handle(handle: Handle) -> WinConsole{
         WinConsole(handle)
     }
+
+    /// Creates a new `WinConsole` with a duplicate of the handle of this console,
+    /// the new handle is owned and closed when the console is dropped.
+    ///
+    /// This is useful to share a console between threads, where each thread owns a valid handle.
+    /// Each call to write to the console is serialized by the system, so the text written from
+    /// multiple threads may interleave but each write is not corrupted.
+    ///
+    /// Wraps a call to [DuplicateHandle](https://docs.microsoft.com/en-us/windows/win32/api/handleapi/nf-handleapi-duplicatehandle).
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle.
+    ///
+    /// # Example
+    /// ```
+    /// use std::thread;
+    /// use win32console::console::WinConsole;
+    ///
+    /// let handles = (0..2).map(|i| {
+    ///     let console = WinConsole::output().try_clone().unwrap();
+    ///     thread::spawn(move || {
+    ///         for _ in 0..10 {
+    ///             console.write_utf8(format!("Thread {}\n", i).as_bytes()).unwrap();
+    ///         }
+    ///     })
+    /// }).collect::<Vec<_>>();
+    ///
+    /// for handle in handles {
+    ///     handle.join().unwrap();
+    /// }
+    /// ```
+    #[inline]
+    pub fn try_clone(&self) -> Result<WinConsole>{
+        self.0.try_clone().map(WinConsole)
+    }
 }
 
 // Public methods
 impl WinConsole {
+    // The maximum number of cells read or written in a single call to `ReadConsoleOutputW` or `WriteConsoleOutputW`,
+    // larger buffers may fail due the size limit of the shared memory used by the console.
+    const MAX_OUTPUT_CELLS: usize = 8 * 1024;
+
+    // The approximate number of input events the console input buffer can hold, there is no API to get
+    // the actual capacity so the default size of the input buffer is used.
+    const INPUT_BUFFER_CAPACITY: usize = 256;
+
+    // The number of remaining input events at which the input buffer is considered near to be full.
+    const INPUT_BUFFER_NEAR_FULL: usize = WinConsole::INPUT_BUFFER_CAPACITY / 8;
+
+    // The first build of Windows 10 that supports 24-bit colors in virtual terminal sequences.
+    const TRUECOLOR_MIN_BUILD: u32 = 14931;
+
     // Associative methods
 
     /// Allocates a new console for the calling process.
@@ -557,6 +894,109 @@ impl WinConsole {
         }
     }
 
+    /// Attaches the calling process to the console of its parent process,
+    /// as when a GUI application is launched from a terminal and wants to write to it.
+    ///
+    /// Wraps a call to [AttachConsole](https://docs.microsoft.com/en-us/windows/console/attachconsole)
+    /// using `ATTACH_PARENT_PROCESS`.
+    ///
+    /// # Errors
+    /// - `ErrorKind::AlreadyExists` if the calling process is already attached to a console, `ERROR_ACCESS_DENIED`.
+    /// - `ErrorKind::NotFound` if the parent process does not have a console, `ERROR_INVALID_HANDLE`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// // In a GUI application: #![windows_subsystem = "windows"]
+    /// use win32console::console::WinConsole;
+    ///
+    /// if std::env::args().any(|arg| arg == "--version") {
+    ///     // Prints the version in the terminal that launched the application, if any
+    ///     if WinConsole::attach_parent_console().is_ok() {
+    ///         WinConsole::output().write_utf8(b"my-app 1.0.0\r\n").unwrap();
+    ///         WinConsole::free_console().unwrap();
+    ///     }
+    /// }
+    /// ```
+    pub fn attach_parent_console() -> Result<()> {
+        WinConsole::attach_console(ATTACH_PARENT_PROCESS).map_err(|e| {
+            match e.raw_os_error().map(|code| code as u32) {
+                Some(ERROR_ACCESS_DENIED) => Error::new(
+                    ErrorKind::AlreadyExists, "the calling process is already attached to a console"),
+                Some(ERROR_INVALID_HANDLE) => Error::new(
+                    ErrorKind::NotFound, "the parent process does not have a console"),
+                _ => e
+            }
+        })
+    }
+
+    /// Requests a graceful shutdown of the processes in the given process group
+    /// by sending a `CTRL_BREAK_EVENT` to the group.
+    ///
+    /// - `process_group_id`: the identifier of the process group to signal, which is the process id of the
+    ///   root process of the group, created with the `CREATE_NEW_PROCESS_GROUP` flag.
+    ///
+    /// Wraps a call to [GenerateConsoleCtrlEvent](https://docs.microsoft.com/en-us/windows/console/generateconsolectrlevent).
+    ///
+    /// # Remarks
+    /// The signal is only received by the processes that share the console of the calling process,
+    /// and a process can be attached to a single console, so to signal a process with its own console
+    /// the supervisor must:
+    /// 1. Detach from its own console using [`free_console`].
+    /// 2. Attach to the console of the child process using [`attach_console`].
+    /// 3. Send the signal using this function.
+    /// 4. Detach from the child console and attach again to the previous console.
+    ///
+    /// # Errors
+    /// - `ErrorKind::InvalidInput` if `process_group_id` is 0, which would signal all the processes
+    ///   attached to the console, including the calling process.
+    /// - If the calling process is not attached to a console.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::os::windows::process::CommandExt;
+    /// use std::process::Command;
+    /// use win32console::console::WinConsole;
+    ///
+    /// const CREATE_NEW_CONSOLE: u32 = 0x00000010;
+    /// const CREATE_NEW_PROCESS_GROUP: u32 = 0x00000200;
+    ///
+    /// let mut child = Command::new("server.exe")
+    ///     .creation_flags(CREATE_NEW_CONSOLE | CREATE_NEW_PROCESS_GROUP)
+    ///     .spawn()
+    ///     .unwrap();
+    ///
+    /// // Detaches from the own console and attaches to the console of the child
+    /// WinConsole::free_console().unwrap();
+    /// WinConsole::attach_console(child.id()).unwrap();
+    ///
+    /// // The child is the root of its process group, so its id is the group id
+    /// WinConsole::request_shutdown(child.id()).unwrap();
+    ///
+    /// // Attaches again to the console of the terminal that launched this process
+    /// WinConsole::free_console().unwrap();
+    /// WinConsole::attach_parent_console().unwrap();
+    ///
+    /// child.wait().unwrap();
+    /// ```
+    ///
+    /// [`free_console`]: #method.free_console
+    /// [`attach_console`]: #method.attach_console
+    pub fn request_shutdown(process_group_id: u32) -> Result<()> {
+        if process_group_id == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "the process group id 0 would signal all the processes attached to the console"));
+        }
+
+        unsafe {
+            if GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, process_group_id) == 0 {
+                Err(Error::last_os_error())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
     /// Detaches the calling process from its console.
     ///
     /// Wraps a call to [FreeConsole](https://docs.microsoft.com/en-us/windows/console/freeconsole).
@@ -573,6 +1013,34 @@ impl WinConsole {
         }
     }
 
+    /// Checks if the calling process has a console attached, either because the console window exists
+    /// or because any of the std handles is a console handle.
+    ///
+    /// [`alloc_console`] and [`attach_console`] fails if the process has a console attached,
+    /// so this can be used to check if [`free_console`] should be called first.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// // Only creates a new console if the process don't have one
+    /// if !WinConsole::has_console() {
+    ///     WinConsole::alloc_console().unwrap();
+    /// }
+    ///
+    /// assert!(WinConsole::has_console());
+    /// ```
+    ///
+    /// [`alloc_console`]: #method.alloc_console
+    /// [`attach_console`]: #method.attach_console
+    /// [`free_console`]: #method.free_console
+    pub fn has_console() -> bool {
+        WinConsole::get_window().is_some()
+            || !WinConsole::is_redirected(HandleType::Input)
+            || !WinConsole::is_redirected(HandleType::Output)
+            || !WinConsole::is_redirected(HandleType::Error)
+    }
+
     /// Sets the title of the current console.
     ///
     /// Wraps a call to [SetConsoleTitle](https://docs.microsoft.com/en-us/windows/console/setconsoletitle).
@@ -657,7 +1125,7 @@ impl WinConsole {
             if length == 0 {
                 Err(Error::last_os_error())
             } else {
-                match String::from_utf16(&buffer) {
+                match String::from_utf16(&buffer[..length.min(buffer.len())]) {
                     Ok(string) => Ok(string),
                     Err(e) => Err(Error::new(ErrorKind::InvalidData, e)),
                 }
@@ -665,12 +1133,41 @@ impl WinConsole {
         }
     }
 
+    /// Sets the title of the current console and returns a [`ScopedTitle`] that restores
+    /// the previous title when is dropped.
+    ///
+    /// # Errors
+    /// - If the current title cannot be retrieved or the new title cannot be set.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// let old_title = WinConsole::get_title().unwrap();
+    /// {
+    ///     let _title = WinConsole::scoped_title("Building... 50%").unwrap();
+    ///     assert_eq!("Building... 50%", WinConsole::get_title().unwrap());
+    /// }
+    ///
+    /// assert_eq!(old_title, WinConsole::get_title().unwrap());
+    /// ```
+    ///
+    /// [`ScopedTitle`]: struct.ScopedTitle.html
+    pub fn scoped_title(title: &str) -> Result<ScopedTitle> {
+        let old_title = WinConsole::get_title()?;
+        WinConsole::set_title(title)?;
+        Ok(ScopedTitle{ old_title })
+    }
+
     /// Retrieves the original title for the current console window.
     ///
     /// Wraps a call to [GetConsoleOriginalTitleW](https://docs.microsoft.com/en-us/windows/console/getconsoleoriginaltitle).
     ///
+    /// # Remarks
+    /// - The buffer used to read the title grows until the whole title fits.
+    ///
     /// # Errors
-    /// - If f the buffer is not large enough to store the title.
+    /// - If the title cannot be retrieved or is not valid UTF-16.
     ///
     /// # Example
     /// ```
@@ -679,17 +1176,37 @@ impl WinConsole {
     /// WinConsole::output().write_utf8(title.as_bytes());
     /// ```
     pub fn get_original_title() -> Result<String> {
-        let mut buffer: [u16; MAX_PATH as usize] = unsafe { MaybeUninit::zeroed().assume_init() };
+        // The title of a console is limited to 64K characters
+        const MAX_TITLE_LENGTH: usize = 64 * 1024;
+        let mut buffer = vec![0_u16; MAX_PATH];
 
-        unsafe {
-            if GetConsoleOriginalTitleW(buffer.as_mut_ptr(), buffer.len() as u32) == 0 {
-                Err(Error::last_os_error())
-            } else {
-                match String::from_utf16(&buffer) {
-                    Ok(string) => Ok(string),
-                    Err(e) => Err(Error::new(ErrorKind::InvalidData, e)),
+        loop {
+            let length = unsafe {
+                GetConsoleOriginalTitleW(buffer.as_mut_ptr(), buffer.len() as u32) as usize
+            };
+
+            if length == 0 {
+                let error = Error::last_os_error();
+
+                // A zero length with no error means the buffer is not large enough
+                if error.raw_os_error() == Some(0) && buffer.len() < MAX_TITLE_LENGTH {
+                    buffer = vec![0_u16; buffer.len() * 2];
+                    continue;
                 }
+
+                return Err(error);
             }
+
+            let title = &buffer[..length.min(buffer.len())];
+            let title = match title.iter().position(|c| *c == 0) {
+                Some(end) => &title[..end],
+                None => title
+            };
+
+            return match String::from_utf16(title) {
+                Ok(string) => Ok(string),
+                Err(e) => Err(Error::new(ErrorKind::InvalidData, e)),
+            };
         }
     }
 
@@ -728,6 +1245,41 @@ impl WinConsole {
         }
     }
 
+    /// Gets the input code page used by the console associated with the calling process as a [`CodePage`].
+    ///
+    /// Wraps a call to [GetConsoleCP](https://docs.microsoft.com/en-us/windows/console/getconsolecp).
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// let code_page = WinConsole::get_input_code_page_typed().unwrap();
+    /// WinConsole::output().write_utf8(format!("{:?}", code_page).as_bytes()).unwrap();
+    /// ```
+    ///
+    /// [`CodePage`]: ../structs/code_page/enum.CodePage.html
+    #[inline]
+    pub fn get_input_code_page_typed() -> Result<CodePage> {
+        WinConsole::get_input_code_page().map(CodePage::from)
+    }
+
+    /// Gets the output code page used by the console associated with the calling process as a [`CodePage`].
+    ///
+    /// Wraps a call to [GetConsoleOutputCP](https://docs.microsoft.com/en-us/windows/console/getconsoleoutputcp).
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// if !WinConsole::get_output_code_page_typed().unwrap().is_utf8(){
+    ///     WinConsole::output().write_utf8(b"The console is not using UTF-8").unwrap();
+    /// }
+    /// ```
+    ///
+    /// [`CodePage`]: ../structs/code_page/enum.CodePage.html
+    #[inline]
+    pub fn get_output_code_page_typed() -> Result<CodePage> {
+        WinConsole::get_output_code_page().map(CodePage::from)
+    }
+
     /// Sets the input code page used by the console associated with the calling process.
     /// A console uses its input code page to translate keyboard input into the corresponding character value.
     ///
@@ -757,42 +1309,140 @@ impl WinConsole {
         }
     }
 
-    /// Retrieves the display mode of the current console.
+    /// Sets both the input and output code pages of the console to `UTF-8` (65001).
     ///
-    /// Wraps a call to [GetConsoleDisplayMode](https://docs.microsoft.com/en-us/windows/console/getconsoledisplaymode).
-    pub fn get_display_mode() -> Result<ConsoleDisplayMode>{
-        unsafe{
-            let mut mode = 0;
-            if GetConsoleDisplayMode(&mut mode) == 0{
-                Err(Error::last_os_error())
-            }
-            else{
-                match mode{
-                    1 => Ok(ConsoleDisplayMode::FullScreen),
-                    2 => Ok(ConsoleDisplayMode::FullScreenHardware),
-                    // For some reason the function always return 0, there is not documentation
-                    // for that value
-                    _ => Ok(ConsoleDisplayMode::Unknown)
-                    //c => panic!("Invalid display mode: {}", c)
-                }
-            }
-        }
-    }
-
-    /// Gets information about the current console selection.
+    /// # Remarks
+    /// - The code pages are changed for the whole console, not only for the handles of this process,
+    ///   so other processes attached to the same console are also affected.
     ///
-    /// Wraps a call to [GetConsoleSelectionInfo](https://docs.microsoft.com/en-us/windows/console/getconsoleselectioninfo).
+    /// # Errors
+    /// - The first error found while setting the code pages.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// let info = WinConsole::get_selection_info().unwrap();
+    /// use win32console::structs::code_page::CodePage;
+    ///
+    /// WinConsole::enable_utf8().unwrap();
+    /// assert_eq!(CodePage::Utf8, WinConsole::get_output_code_page_typed().unwrap());
     /// ```
-    pub fn get_selection_info() -> Result<ConsoleSelectionInfo> {
-        unsafe {
-            let mut info: CONSOLE_SELECTION_INFO = std::mem::zeroed();
+    pub fn enable_utf8() -> Result<()> {
+        WinConsole::set_input_code(CodePage::Utf8.into())?;
+        WinConsole::set_output_code(CodePage::Utf8.into())
+    }
 
-            if GetConsoleSelectionInfo(&mut info) == 0 {
+    /// Sets both the input and output code pages of the console to `UTF-8` (65001), runs the
+    /// given function and then restores the previous code pages.
+    ///
+    /// # Remarks
+    /// - The code pages are changed for the whole console, not only for the handles of this process,
+    ///   so other processes attached to the same console are also affected.
+    /// - The previous code pages are also restored if the function panics.
+    ///
+    /// # Errors
+    /// - The first error found while getting, setting or restoring the code pages.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// let old_code_page = WinConsole::get_output_code_page().unwrap();
+    /// WinConsole::with_utf8(|| {
+    ///     WinConsole::output().write_utf8("Olá, café, 日本".as_bytes()).unwrap();
+    /// }).unwrap();
+    ///
+    /// assert_eq!(old_code_page, WinConsole::get_output_code_page().unwrap());
+    /// ```
+    pub fn with_utf8<F, R>(f: F) -> Result<R> where F: FnOnce() -> R {
+        let input_code_page = WinConsole::get_input_code_page()?;
+        let output_code_page = WinConsole::get_output_code_page()?;
+
+        WinConsole::set_input_code(CodePage::Utf8.into())?;
+        let mut guard = CodePageGuard{ input_code_page: Some(input_code_page), output_code_page: None };
+
+        // If fails the guard restores the input code page
+        WinConsole::set_output_code(CodePage::Utf8.into())?;
+        guard.output_code_page = Some(output_code_page);
+
+        let result = f();
+        guard.restore()?;
+        Ok(result)
+    }
+
+    /// Sets the output code page of the console to the specified value, runs the given function
+    /// and then restores the previous output code page.
+    ///
+    /// - `code_page`: the identifier of the code page to use while the function runs.
+    ///
+    /// # Remarks
+    /// - The code page is changed for the whole console, not only for the handles of this process,
+    ///   so other processes attached to the same console are also affected.
+    /// - The previous code page is also restored if the function panics.
+    ///
+    /// # Errors
+    /// - The first error found while getting, setting or restoring the code page.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::code_page::CodePage;
+    ///
+    /// let old_code_page = WinConsole::get_output_code_page().unwrap();
+    /// let code_page = WinConsole::with_output_code_page(CodePage::Utf8.into(), || {
+    ///     WinConsole::output().write_utf8("Olá, café, 日本".as_bytes()).unwrap();
+    ///     WinConsole::get_output_code_page().unwrap()
+    /// }).unwrap();
+    ///
+    /// assert_eq!(65001, code_page);
+    /// assert_eq!(old_code_page, WinConsole::get_output_code_page().unwrap());
+    /// ```
+    pub fn with_output_code_page<F, R>(code_page: u32, f: F) -> Result<R> where F: FnOnce() -> R {
+        let output_code_page = WinConsole::get_output_code_page()?;
+
+        WinConsole::set_output_code(code_page)?;
+        let guard = CodePageGuard{ input_code_page: None, output_code_page: Some(output_code_page) };
+
+        let result = f();
+        guard.restore()?;
+        Ok(result)
+    }
+
+    /// Retrieves the display mode of the current console.
+    ///
+    /// Wraps a call to [GetConsoleDisplayMode](https://docs.microsoft.com/en-us/windows/console/getconsoledisplaymode).
+    pub fn get_display_mode() -> Result<ConsoleDisplayMode>{
+        unsafe{
+            let mut mode = 0;
+            if GetConsoleDisplayMode(&mut mode) == 0{
+                Err(Error::last_os_error())
+            }
+            else{
+                match mode{
+                    1 => Ok(ConsoleDisplayMode::FullScreen),
+                    2 => Ok(ConsoleDisplayMode::FullScreenHardware),
+                    // For some reason the function always return 0, there is not documentation
+                    // for that value
+                    _ => Ok(ConsoleDisplayMode::Unknown)
+                    //c => panic!("Invalid display mode: {}", c)
+                }
+            }
+        }
+    }
+
+    /// Gets information about the current console selection.
+    ///
+    /// Wraps a call to [GetConsoleSelectionInfo](https://docs.microsoft.com/en-us/windows/console/getconsoleselectioninfo).
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// let info = WinConsole::get_selection_info().unwrap();
+    /// ```
+    pub fn get_selection_info() -> Result<ConsoleSelectionInfo> {
+        unsafe {
+            let mut info: CONSOLE_SELECTION_INFO = std::mem::zeroed();
+
+            if GetConsoleSelectionInfo(&mut info) == 0 {
                 Err(Error::last_os_error())
             } else {
                 Ok(ConsoleSelectionInfo::from(info))
@@ -800,6 +1450,65 @@ impl WinConsole {
         }
     }
 
+    /// Gets the anchor of the current console selection, if a selection is in progress.
+    ///
+    /// Wraps a call to [GetConsoleSelectionInfo](https://docs.microsoft.com/en-us/windows/console/getconsoleselectioninfo).
+    ///
+    /// # Remarks
+    /// - This reflects the selection made by the user with the mouse or the keyboard in the console window,
+    ///   not a selection made by this process.
+    ///
+    /// # Returns
+    /// The cell where the selection started or `None` if there is no selection in progress.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// if let Some(anchor) = WinConsole::get_selection_anchor().unwrap(){
+    ///     println!("Selection started at {}", anchor);
+    /// }
+    /// ```
+    pub fn get_selection_anchor() -> Result<Option<Coord>> {
+        let info = WinConsole::get_selection_info()?;
+
+        if info.selection_indicator.selection_in_progress() {
+            Ok(Some(info.selection_anchor))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Gets the rectangle of the current console selection, if a selection is in progress and is not empty.
+    ///
+    /// Wraps a call to [GetConsoleSelectionInfo](https://docs.microsoft.com/en-us/windows/console/getconsoleselectioninfo).
+    ///
+    /// # Remarks
+    /// - This reflects the selection made by the user with the mouse or the keyboard in the console window,
+    ///   not a selection made by this process.
+    ///
+    /// # Returns
+    /// The selected cells or `None` if there is no selection or the selection is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// if let Some(rect) = WinConsole::get_selection_rect().unwrap(){
+    ///     println!("Selected {}x{} cells", rect.width(), rect.height());
+    /// }
+    /// ```
+    pub fn get_selection_rect() -> Result<Option<SmallRect>> {
+        let info = WinConsole::get_selection_info()?;
+        let state = info.selection_indicator;
+
+        if state.selection_in_progress() && state.selection_not_empty() {
+            Ok(Some(info.selection_rect))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Creates a new console screen buffer with:
     /// - `dwDesiredAccess` = GENERIC_READ | GENERIC_WRITE`
     /// - `dwShareMode` = FILE_SHARE_READ | FILE_SHARE_WRITE
@@ -873,6 +1582,32 @@ impl WinConsole {
         }
     }
 
+    /// Gets the screen buffer info of the given handle, without wrap it in a `WinConsole`.
+    ///
+    /// Wraps a call to [GetConsoleScreenBufferInfo](https://docs.microsoft.com/en-us/windows/console/getconsolescreenbufferinfo).
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or a console input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// let handle = WinConsole::create_console_screen_buffer().unwrap();
+    /// let info = WinConsole::screen_buffer_info_for(&handle).unwrap();
+    /// assert_eq!(0, info.cursor_position.x);
+    /// ```
+    pub fn screen_buffer_info_for(handle: &Handle) -> Result<ConsoleScreenBufferInfo> {
+        unsafe {
+            let mut info: CONSOLE_SCREEN_BUFFER_INFO = std::mem::zeroed();
+            if GetConsoleScreenBufferInfo(**handle, &mut info) != 0 {
+                Ok(ConsoleScreenBufferInfo::from(info))
+            } else {
+                Err(Error::last_os_error())
+            }
+        }
+    }
+
     /// Sets the specified screen buffer to be the currently displayed console screen buffer.
     ///
     /// Wraps a call to [SetConsoleActiveScreenBuffer](https://docs.microsoft.com/en-us/windows/console/setconsoleactivescreenbuffer).
@@ -886,6 +1621,49 @@ impl WinConsole {
         }
     }
 
+    /// Creates a new screen buffer and makes it the active screen buffer, runs the given function
+    /// passing a `WinConsole` for the new screen buffer, and then restores the screen buffer
+    /// that was active before.
+    ///
+    /// This allows to display a full screen UI and then return to the previous content of the console,
+    /// as an alternate screen.
+    ///
+    /// Wraps a call to [CreateConsoleScreenBuffer](https://docs.microsoft.com/en-us/windows/console/createconsolescreenbuffer)
+    /// and [SetConsoleActiveScreenBuffer](https://docs.microsoft.com/en-us/windows/console/setconsoleactivescreenbuffer).
+    ///
+    /// # Remarks
+    /// - The previous active screen buffer is restored also if the function panics.
+    /// - The calls can be nested, each one restores the screen buffer that was active before it.
+    /// - The new screen buffer is closed after the function returns.
+    ///
+    /// # Errors
+    /// - If the screen buffer cannot be created, or the active screen buffer cannot be changed or restored.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use win32console::console::WinConsole;
+    ///
+    /// WinConsole::with_alternate_screen(|screen| {
+    ///     screen.write_utf8(b"Alternate screen").unwrap();
+    ///     std::thread::sleep(Duration::from_secs(1));
+    /// }).unwrap();
+    ///
+    /// WinConsole::output().write_utf8(b"Back to the main screen").unwrap();
+    /// ```
+    pub fn with_alternate_screen<F, R>(f: F) -> Result<R> where F: FnOnce(&WinConsole) -> R {
+        // `CONOUT$` refers to the active screen buffer, which may not be the standard output
+        let active = WinConsole::get_current_output_handle()?;
+        let screen = WinConsole::with_handle(WinConsole::create_console_screen_buffer()?);
+
+        WinConsole::set_active_console_screen_buffer(screen.get_handle())?;
+        let guard = ActiveScreenBufferGuard{ handle: Some(active) };
+
+        let result = f(&screen);
+        guard.restore()?;
+        Ok(result)
+    }
+
     /// Retrieves a list of the processes attached to the current console.
     ///
     /// Wraps a call to [GetConsoleProcessList](https://docs.microsoft.com/en-us/windows/console/getconsoleprocesslist).
@@ -1004,6 +1782,273 @@ impl WinConsole {
         }
     }
 
+    /// Checks if the standard output is being redirected, for example to a file or a pipe.
+    ///
+    /// Returns `true` if the `STD_OUTPUT_HANDLE` is not a console handle.
+    ///
+    /// # Example
+    /// ```
+    /// use std::fs::File;
+    /// use std::os::windows::io::AsRawHandle;
+    /// use win32console::console::{WinConsole, HandleType};
+    /// use win32console::structs::handle::Handle;
+    ///
+    /// assert!(!WinConsole::is_output_redirected());
+    ///
+    /// // Redirects the output to a file
+    /// let path = std::env::temp_dir().join("output.txt");
+    /// let std_output = WinConsole::get_std_handle(HandleType::Output).unwrap();
+    /// let file = File::create(&path).unwrap();
+    /// WinConsole::set_std_handle(HandleType::Output, Handle::new(file.as_raw_handle() as _)).unwrap();
+    /// assert!(WinConsole::is_output_redirected());
+    ///
+    /// // Restores the output
+    /// WinConsole::set_std_handle(HandleType::Output, std_output).unwrap();
+    /// assert!(!WinConsole::is_output_redirected());
+    ///
+    /// drop(file);
+    /// std::fs::remove_file(path).unwrap();
+    /// ```
+    #[inline]
+    pub fn is_output_redirected() -> bool {
+        WinConsole::is_redirected(HandleType::Output)
+    }
+
+    /// Checks if the standard input is being redirected, for example from a file or a pipe.
+    ///
+    /// Returns `true` if the `STD_INPUT_HANDLE` is not a console handle.
+    ///
+    /// # Example
+    /// ```
+    /// use std::fs::File;
+    /// use std::os::windows::io::AsRawHandle;
+    /// use win32console::console::{WinConsole, HandleType};
+    /// use win32console::structs::handle::Handle;
+    ///
+    /// assert!(!WinConsole::is_input_redirected());
+    ///
+    /// // Redirects the input from a file
+    /// let path = std::env::temp_dir().join("input.txt");
+    /// let std_input = WinConsole::get_std_handle(HandleType::Input).unwrap();
+    /// let file = File::create(&path).unwrap();
+    /// WinConsole::set_std_handle(HandleType::Input, Handle::new(file.as_raw_handle() as _)).unwrap();
+    /// assert!(WinConsole::is_input_redirected());
+    ///
+    /// // Restores the input
+    /// WinConsole::set_std_handle(HandleType::Input, std_input).unwrap();
+    /// assert!(!WinConsole::is_input_redirected());
+    ///
+    /// drop(file);
+    /// std::fs::remove_file(path).unwrap();
+    /// ```
+    #[inline]
+    pub fn is_input_redirected() -> bool {
+        WinConsole::is_redirected(HandleType::Input)
+    }
+
+    /// Gets the character produced by the given virtual key code and control keys state,
+    /// using the current keyboard layout of the calling thread.
+    ///
+    /// Useful when a key event have a zero `u_char`, as may happen with some keyboard layouts or IMEs.
+    ///
+    /// Wraps a call to [MapVirtualKeyW](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-mapvirtualkeyw)
+    /// and [ToUnicode](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-tounicode).
+    ///
+    /// # Returns
+    /// The character produced or `None` if the key don't produce a character, like the function keys,
+    /// the arrow keys or a dead key. The control characters are returned as they are produced,
+    /// for example `\r` for the Enter key.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::input_event::ControlKeyState;
+    ///
+    /// // Virtual key code of the `A` key
+    /// assert_eq!(Some('A'), WinConsole::vk_to_char(0x41, &ControlKeyState::none().with_shift()));
+    /// // Virtual key code of the `F1` key
+    /// assert_eq!(None, WinConsole::vk_to_char(0x70, &ControlKeyState::none()));
+    /// ```
+    pub fn vk_to_char(virtual_key_code: u16, control_key_state: &ControlKeyState) -> Option<char> {
+        const KEY_DOWN: u8 = 0x80;
+        const KEY_TOGGLED: u8 = 0x01;
+        // Don't change the keyboard state, so dead keys are not combined with the next key
+        const NO_STATE_CHANGE: u32 = 0x04;
+
+        let mut key_state = [0_u8; 256];
+
+        if control_key_state.is_shift_pressed() {
+            key_state[VK_SHIFT as usize] = KEY_DOWN;
+        }
+
+        if control_key_state.has_state(ControlKeyState::LEFT_CTRL_PRESSED) {
+            key_state[VK_LCONTROL as usize] = KEY_DOWN;
+            key_state[VK_CONTROL as usize] = KEY_DOWN;
+        }
+
+        if control_key_state.has_state(ControlKeyState::RIGHT_CTRL_PRESSED) {
+            key_state[VK_RCONTROL as usize] = KEY_DOWN;
+            key_state[VK_CONTROL as usize] = KEY_DOWN;
+        }
+
+        if control_key_state.has_state(ControlKeyState::LEFT_ALT_PRESSED) {
+            key_state[VK_LMENU as usize] = KEY_DOWN;
+            key_state[VK_MENU as usize] = KEY_DOWN;
+        }
+
+        if control_key_state.has_state(ControlKeyState::RIGHT_ALT_PRESSED) {
+            key_state[VK_RMENU as usize] = KEY_DOWN;
+            key_state[VK_MENU as usize] = KEY_DOWN;
+        }
+
+        if control_key_state.is_caps_lock_on() {
+            key_state[VK_CAPITAL as usize] = KEY_TOGGLED;
+        }
+
+        if control_key_state.is_num_lock_on() {
+            key_state[VK_NUMLOCK as usize] = KEY_TOGGLED;
+        }
+
+        let mut buf = [0_u16; 8];
+
+        let len = unsafe {
+            let scan_code = MapVirtualKeyW(virtual_key_code as u32, MAPVK_VK_TO_VSC);
+            ToUnicode(
+                virtual_key_code as u32,
+                scan_code,
+                key_state.as_ptr(),
+                buf.as_mut_ptr(),
+                buf.len() as i32,
+                NO_STATE_CHANGE)
+        };
+
+        // A negative value is a dead key and zero is a key that don't produce a character
+        if len <= 0 {
+            return None;
+        }
+
+        let mut chars = std::char::decode_utf16(buf[..len as usize].iter().cloned());
+        match (chars.next(), chars.next()) {
+            (Some(Ok(c)), None) => Some(c),
+            _ => None
+        }
+    }
+
+    /// Wraps the given text into lines of at most `width` characters, breaking the lines between words,
+    /// useful to lay out text in a region of fixed width before write it.
+    ///
+    /// # Remarks
+    /// - Each line of the text is wrapped independently, so the newlines are preserved.
+    /// - The words are separated by a single space, any other whitespace between words is discarded.
+    /// - The words longer than `width` are split across several lines.
+    /// - The width is measured in `char`s, so wide characters may take more columns in the console.
+    ///
+    /// # Panics
+    /// - If `width` is not greater than 0.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// let lines = WinConsole::wrap_text("The quick brown fox jumps over the lazy dog", 15);
+    /// assert_eq!(vec!["The quick brown", "fox jumps over", "the lazy dog"], lines);
+    /// ```
+    pub fn wrap_text(text: &str, width: i16) -> Vec<String> {
+        assert!(width > 0, "the width must be greater than 0: {}", width);
+
+        let width = width as usize;
+        let mut lines = Vec::new();
+
+        for paragraph in text.lines() {
+            let mut line = String::new();
+            let mut line_len = 0;
+
+            for word in paragraph.split_whitespace() {
+                let word_len = word.chars().count();
+
+                if line_len > 0 && line_len + 1 + word_len <= width {
+                    line.push(' ');
+                    line.push_str(word);
+                    line_len += 1 + word_len;
+                    continue;
+                }
+
+                if line_len > 0 {
+                    lines.push(std::mem::take(&mut line));
+                }
+
+                // Splits the words that don't fit in a single line
+                let mut chars = word.chars().peekable();
+                loop {
+                    line = chars.by_ref().take(width).collect();
+                    line_len = line.chars().count();
+
+                    if chars.peek().is_none() {
+                        break;
+                    }
+
+                    lines.push(std::mem::take(&mut line));
+                }
+            }
+
+            lines.push(line);
+        }
+
+        lines
+    }
+
+    /// Gets the features supported by the console host, by probing the modes of the standard
+    /// input and output handles and checking the build of Windows, the previous console modes are always restored.
+    ///
+    /// Any feature is reported as not supported if the handle it requires is redirected.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// let capabilities = WinConsole::capabilities();
+    /// if capabilities.truecolor {
+    ///     WinConsole::output().write_utf8("\x1b[38;2;255;128;0mOrange\x1b[0m".as_bytes()).unwrap();
+    /// }
+    /// ```
+    pub fn capabilities() -> ConsoleCapabilities {
+        let input = WinConsole::input();
+        let output = WinConsole::output();
+        let virtual_terminal = output.supports_virtual_terminal();
+
+        // The first builds that process virtual terminal sequences ignore the 24-bit colors
+        let truecolor = virtual_terminal
+            && matches!(WinConsole::windows_build_number(), Some(build) if build >= WinConsole::TRUECOLOR_MIN_BUILD);
+
+        ConsoleCapabilities {
+            virtual_terminal,
+            truecolor,
+            mouse_input: input.supports_mode(ConsoleMode::ENABLE_MOUSE_INPUT),
+            lvb_grid: output.supports_mode(ConsoleMode::ENABLE_LVB_GRID_WORLDWIDE),
+        }
+    }
+
+    /// Gets the build number of Windows, or `None` if cannot be read.
+    fn windows_build_number() -> Option<u32> {
+        let mut info : OSVERSIONINFOW = unsafe { std::mem::zeroed() };
+        info.dwOSVersionInfoSize = std::mem::size_of::<OSVERSIONINFOW>() as u32;
+
+        // STATUS_SUCCESS
+        if unsafe { RtlGetVersion(&mut info) } == 0 {
+            Some(info.dwBuildNumber)
+        } else {
+            None
+        }
+    }
+
+    /// Checks if the std handle of the given type is not a console handle.
+    fn is_redirected(handle_type: HandleType) -> bool {
+        match WinConsole::get_std_handle(handle_type) {
+            Ok(handle) => !WinConsole::is_console(&handle),
+            Err(_) => true,
+        }
+    }
+
     /// Gets the current console mode.
     ///
     /// # Remarks
@@ -1063,11 +2108,112 @@ impl WinConsole {
         }
     }
 
-    // Instance methods
-
-    /// Gets the handle used for this console, which will be provided by the `handle_provider`.
-    pub fn get_handle(&self) -> &Handle {
-        &self.0
+    /// Moves the console window to the given position in pixels, relative to the top-left corner of the screen.
+    ///
+    /// Wraps a call to [SetWindowPos](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowpos).
+    ///
+    /// # Remarks
+    /// - Requires a real console window, this function fails in a headless session
+    ///   or when the process has no console attached.
+    ///
+    /// # Errors
+    /// - `ErrorKind::NotFound` if there is no console window.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// WinConsole::set_window_top_left_pixels(0, 0).unwrap();
+    /// ```
+    pub fn set_window_top_left_pixels(x: i32, y: i32) -> Result<()>{
+        WinConsole::set_window_pos(null_mut(), x, y, SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE)
+    }
+
+    /// Sets whether the console window is placed above all the non-topmost windows,
+    /// the window keeps its topmost position even when is deactivated.
+    ///
+    /// Wraps a call to [SetWindowPos](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowpos).
+    ///
+    /// # Remarks
+    /// - Requires a real console window, this function fails in a headless session
+    ///   or when the process has no console attached.
+    ///
+    /// # Errors
+    /// - `ErrorKind::NotFound` if there is no console window.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// WinConsole::set_window_always_on_top(true).unwrap();
+    /// WinConsole::output().write_utf8(b"I'm always visible").unwrap();
+    /// WinConsole::set_window_always_on_top(false).unwrap();
+    /// ```
+    pub fn set_window_always_on_top(enable: bool) -> Result<()>{
+        let insert_after = if enable { HWND_TOPMOST } else { HWND_NOTOPMOST };
+        WinConsole::set_window_pos(insert_after, 0, 0, SWP_NOSIZE | SWP_NOMOVE | SWP_NOACTIVATE)
+    }
+
+    /// Calls `SetWindowPos` on the console window with the given values.
+    fn set_window_pos(insert_after: *mut HWND__, x: i32, y: i32, flags: u32) -> Result<()>{
+        match WinConsole::get_window(){
+            Some(handle) => {
+                unsafe{
+                    if SetWindowPos(*handle as *mut HWND__, insert_after, x, y, 0, 0, flags) == 0{
+                        Err(Error::last_os_error())
+                    }
+                    else{
+                        Ok(())
+                    }
+                }
+            },
+            None => {
+                Err(Error::new(ErrorKind::NotFound, "Cannot get the window handle"))
+            }
+        }
+    }
+
+    // Instance methods
+
+    /// Gets the handle used for this console, which will be provided by the `handle_provider`.
+    pub fn get_handle(&self) -> &Handle {
+        &self.0
+    }
+
+    /// Gets whether the handle of this console is valid and if can be used as a console input
+    /// or output handle, useful to find out why a method fails with `ERROR_INVALID_HANDLE`.
+    ///
+    /// The handle is probed by calling [GetFileType](https://docs.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getfiletype),
+    /// [GetConsoleMode](https://docs.microsoft.com/en-us/windows/console/getconsolemode),
+    /// [GetNumberOfConsoleInputEvents](https://docs.microsoft.com/en-us/windows/console/getnumberofconsoleinputevents)
+    /// and [GetConsoleScreenBufferInfo](https://docs.microsoft.com/en-us/windows/console/getconsolescreenbufferinfo).
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// let info = WinConsole::input().describe_handle();
+    /// if info.is_output {
+    ///     WinConsole::output().write_utf8(b"The input handle is a screen buffer").unwrap();
+    /// }
+    /// ```
+    pub fn describe_handle(&self) -> HandleInfo {
+        let handle = self.get_handle();
+
+        let is_valid = !handle.is_null() && **handle != INVALID_HANDLE_VALUE && unsafe {
+            // Returns `FILE_TYPE_UNKNOWN` and sets `NO_ERROR` for valid handles of an unknown type
+            GetFileType(**handle) != FILE_TYPE_UNKNOWN || Error::last_os_error().raw_os_error() == Some(0)
+        };
+
+        if !is_valid {
+            return HandleInfo { is_valid, is_console: false, is_input: false, is_output: false };
+        }
+
+        HandleInfo {
+            is_valid,
+            is_console: WinConsole::is_console(handle),
+            is_input: WinConsole::is_input_console(handle),
+            is_output: WinConsole::is_output_console(handle)
+        }
     }
 
     /// Gets the current mode of the console
@@ -1135,6 +2281,226 @@ impl WinConsole {
         }
     }
 
+    /// Enables the raw input mode by disabling the `ENABLE_PROCESSED_INPUT`, `ENABLE_LINE_INPUT`
+    /// and `ENABLE_ECHO_INPUT` modes, and returns a [`ModeGuard`] that restores the previous mode when is dropped.
+    ///
+    /// In raw mode the input is available as soon as each key is pressed, without waiting for `Enter`,
+    /// and the characters are not displayed in the console.
+    ///
+    /// # Remarks
+    /// - While the raw mode is active `Ctrl+C` is not processed by the system, instead is placed
+    ///   in the input buffer as any other key, so the caller is responsible of handle it.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    ///   the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::{WinConsole, ConsoleMode};
+    ///
+    /// let input = WinConsole::input();
+    /// let old_mode = input.get_mode().unwrap();
+    /// {
+    ///     let _raw = input.scoped_raw_mode().unwrap();
+    ///     assert!(!input.has_mode(ConsoleMode::ENABLE_LINE_INPUT).unwrap());
+    /// }
+    ///
+    /// assert_eq!(old_mode, input.get_mode().unwrap());
+    /// ```
+    ///
+    /// [`ModeGuard`]: struct.ModeGuard.html
+    pub fn scoped_raw_mode(&self) -> Result<ModeGuard<'_>> {
+        let old_mode = self.get_mode()?;
+        let raw_mode = old_mode & !(ConsoleMode::ENABLE_PROCESSED_INPUT
+            | ConsoleMode::ENABLE_LINE_INPUT
+            | ConsoleMode::ENABLE_ECHO_INPUT);
+
+        self.set_mode(raw_mode)?;
+        Ok(ModeGuard{ console: self, old_mode })
+    }
+
+    /// Sets the mode of a console input handle, the same as [`set_mode`] but making explicit
+    /// that `mode` is a combination of the input mode flags:
+    /// - `ConsoleMode::ENABLE_PROCESSED_INPUT`
+    /// - `ConsoleMode::ENABLE_LINE_INPUT`
+    /// - `ConsoleMode::ENABLE_ECHO_INPUT`
+    /// - `ConsoleMode::ENABLE_WINDOW_INPUT`
+    /// - `ConsoleMode::ENABLE_MOUSE_INPUT`
+    /// - `ConsoleMode::ENABLE_INSERT_MODE`
+    /// - `ConsoleMode::ENABLE_QUICK_EDIT_MODE`
+    /// - `ConsoleMode::ENABLE_EXTENDED_FLAGS`
+    /// - `ConsoleMode::ENABLE_VIRTUAL_TERMINAL_INPUT`
+    ///
+    /// Wraps a call to [SetConsoleMode](https://docs.microsoft.com/en-us/windows/console/setconsolemode).
+    ///
+    /// # Panics
+    /// - In debug builds, if the handle is a console output handle: `WinConsole::output()`.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    ///   the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::{WinConsole, ConsoleMode};
+    ///
+    /// let old_mode = WinConsole::input().get_mode().unwrap();
+    /// WinConsole::input().set_input_mode(old_mode | ConsoleMode::ENABLE_MOUSE_INPUT).unwrap();
+    /// WinConsole::input().set_input_mode(old_mode).unwrap();
+    /// ```
+    ///
+    /// [`set_mode`]: #method.set_mode
+    pub fn set_input_mode(&self, mode: u32) -> Result<()> {
+        debug_assert!(!WinConsole::is_output_console(self.get_handle()),
+                      "set_input_mode was called with a console output handle");
+
+        self.set_mode(mode)
+    }
+
+    /// Sets the mode of a console output handle, the same as [`set_mode`] but making explicit
+    /// that `mode` is a combination of the output mode flags:
+    /// - `ConsoleMode::ENABLE_PROCESSED_OUTPUT`
+    /// - `ConsoleMode::ENABLE_WRAP_AT_EOL_OUTPUT`
+    /// - `ConsoleMode::ENABLE_VIRTUAL_TERMINAL_PROCESSING`
+    /// - `ConsoleMode::DISABLE_NEWLINE_AUTO_RETURN`
+    /// - `ConsoleMode::ENABLE_LVB_GRID_WORLDWIDE`
+    ///
+    /// Wraps a call to [SetConsoleMode](https://docs.microsoft.com/en-us/windows/console/setconsolemode).
+    ///
+    /// # Panics
+    /// - In debug builds, if the handle is a console input handle: `WinConsole::input()`.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::{WinConsole, ConsoleMode};
+    ///
+    /// let old_mode = WinConsole::output().get_mode().unwrap();
+    /// WinConsole::output().set_output_mode(old_mode & !ConsoleMode::ENABLE_WRAP_AT_EOL_OUTPUT).unwrap();
+    /// WinConsole::output().set_output_mode(old_mode).unwrap();
+    /// ```
+    ///
+    /// [`set_mode`]: #method.set_mode
+    pub fn set_output_mode(&self, mode: u32) -> Result<()> {
+        debug_assert!(!WinConsole::is_input_console(self.get_handle()),
+                      "set_output_mode was called with a console input handle");
+
+        self.set_mode(mode)
+    }
+
+    /// Checks if the console supports the virtual terminal sequences, by trying to enable
+    /// the `ENABLE_VIRTUAL_TERMINAL_PROCESSING` mode, the previous console mode is always restored.
+    ///
+    /// Returns `false` if the handle is not a console output handle or the console host don't support
+    /// the virtual terminal sequences, like older versions of Windows 10 or legacy consoles.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// let mode = WinConsole::output().get_mode().unwrap();
+    /// if WinConsole::output().supports_virtual_terminal(){
+    ///     WinConsole::output().write_utf8(b"Virtual terminal supported").unwrap();
+    /// }
+    ///
+    /// assert_eq!(mode, WinConsole::output().get_mode().unwrap());
+    /// ```
+    #[inline]
+    pub fn supports_virtual_terminal(&self) -> bool {
+        self.supports_mode(ConsoleMode::ENABLE_VIRTUAL_TERMINAL_PROCESSING)
+    }
+
+    /// Enables or disables the wrapping of the text at the end of the line by setting the
+    /// `ConsoleMode::ENABLE_WRAP_AT_EOL_OUTPUT` output mode.
+    ///
+    /// When disabled, the text written past the right edge of the screen buffer overwrites the
+    /// last column instead of continue in the next line, useful for status lines and fixed layouts.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// WinConsole::output().set_wrap_at_eol(false).unwrap();
+    /// WinConsole::output().write_utf8("A very long status line that will not scroll the screen".as_bytes()).unwrap();
+    /// WinConsole::output().set_wrap_at_eol(true).unwrap();
+    /// ```
+    #[inline]
+    pub fn set_wrap_at_eol(&self, enable: bool) -> Result<()> {
+        self.set_output_mode_flag(ConsoleMode::ENABLE_WRAP_AT_EOL_OUTPUT, enable)
+    }
+
+    /// Enables or disables the `ConsoleMode::DISABLE_NEWLINE_AUTO_RETURN` output mode.
+    ///
+    /// When enabled, writing to the last column of a line leaves the cursor in that column
+    /// until the next character is written, instead of move it to the next line immediately,
+    /// so a full screen application can write the last cell of the screen without scroll it.
+    ///
+    /// # Remarks
+    /// - The mode only takes effect while `ConsoleMode::ENABLE_VIRTUAL_TERMINAL_PROCESSING` is enabled.
+    /// - When enabled a `\n` only moves the cursor down, so the lines should end with `\r\n`.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - If the console host don't support the mode, like older versions of Windows 10.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::{WinConsole, ConsoleMode};
+    ///
+    /// let old_mode = WinConsole::output().get_mode().unwrap();
+    /// WinConsole::output().set_mode(old_mode | ConsoleMode::ENABLE_VIRTUAL_TERMINAL_PROCESSING).unwrap();
+    /// WinConsole::output().set_disable_newline_auto_return(true).unwrap();
+    ///
+    /// WinConsole::output().write_utf8(b"\x1b[999;999HX").unwrap();
+    /// WinConsole::output().set_mode(old_mode).unwrap();
+    /// ```
+    #[inline]
+    pub fn set_disable_newline_auto_return(&self, enable: bool) -> Result<()> {
+        self.set_output_mode_flag(ConsoleMode::DISABLE_NEWLINE_AUTO_RETURN, enable)
+    }
+
+    /// Sets or clears the given flag of the output mode, the mode is only set if changes.
+    fn set_output_mode_flag(&self, flag: u32, enable: bool) -> Result<()> {
+        let mode = self.get_mode()?;
+        let new_mode = if enable { mode | flag } else { mode & !flag };
+
+        if new_mode == mode {
+            return Ok(());
+        }
+
+        self.set_output_mode(new_mode)
+    }
+
+    /// Checks if the console supports the given mode by trying to enable it,
+    /// the previous console mode is always restored.
+    fn supports_mode(&self, flag: u32) -> bool {
+        let old_mode = match self.get_mode() {
+            Ok(mode) => mode,
+            Err(_) => return false,
+        };
+
+        if old_mode & flag != 0 {
+            return true;
+        }
+
+        let supported = self.set_mode(old_mode | flag).is_ok()
+            && self.get_mode()
+                .map(|mode| mode & flag != 0)
+                .unwrap_or(false);
+
+        let _ = self.set_mode(old_mode);
+        supported
+    }
+
     /// Checks if the console have the specified mode.
     ///
     /// # Errors
@@ -1221,6 +2587,29 @@ impl WinConsole {
         }
     }
 
+    /// Sets the typeface and the size of the console font, where `width` and `height` are the size
+    /// of each character in logical units, the weight and family of the current font are preserved.
+    ///
+    /// Wraps a call to [SetCurrentConsoleFontEx](https://docs.microsoft.com/en-us/windows/console/setcurrentconsolefontex).
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// WinConsole::output().set_font("Consolas", 0, 16).unwrap();
+    /// assert_eq!("Consolas", WinConsole::output().get_font_ex(false).unwrap().font_name());
+    /// ```
+    pub fn set_font(&self, name: &str, width: i16, height: i16) -> Result<()> {
+        let mut info = self.get_font_ex(false)?;
+        info.set_font_name(name);
+        info.font_size = Coord::new(width, height);
+        self.set_font_ex(info, false)
+    }
+
     /// Gets information about the console font.
     ///
     /// Wraps a call to [GetCurrentConsoleFont](https://docs.microsoft.com/en-us/windows/console/getcurrentconsolefont).
@@ -1269,8 +2658,10 @@ impl WinConsole {
         let handle = self.get_handle();
 
         unsafe {
+            debug_assert_eq!(std::mem::size_of::<CONSOLE_FONT_INFOEX>(), std::mem::size_of::<ConsoleFontInfoEx>());
+
             let mut info: CONSOLE_FONT_INFOEX = std::mem::zeroed();
-            info.cbSize = std::mem::size_of::<ConsoleFontInfoEx>() as u32;
+            info.cbSize = std::mem::size_of::<CONSOLE_FONT_INFOEX>() as u32;
 
             let ptr: *mut CONSOLE_FONT_INFOEX = &mut info;
 
@@ -1311,6 +2702,33 @@ impl WinConsole {
         }
     }
 
+    /// Estimates the size in pixels of the given number of character cells, where `x` is the number of columns
+    /// and `y` the number of rows, by multiplying them by the size of the current console font.
+    ///
+    /// # Remarks
+    /// - The result is only an estimate, the actual size of the console window may be different due the padding
+    ///   of the window and the DPI scaling of the display.
+    ///
+    /// # Returns
+    /// The `(width, height)` in pixels.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// let window_size = WinConsole::output().get_window_size().unwrap();
+    /// let (width, height) = WinConsole::output().cells_to_pixels(window_size).unwrap();
+    /// WinConsole::output().write_utf8(format!("Window size: {}x{} pixels", width, height).as_bytes()).unwrap();
+    /// ```
+    pub fn cells_to_pixels(&self, cells: Coord) -> Result<(i32, i32)>{
+        let font_size = self.get_font(false)?.font_size;
+        Ok(WinConsole::scale_cells(cells, font_size))
+    }
+
     /// Gets the current screen buffer info.
     ///
     /// # Errors
@@ -1323,17 +2741,9 @@ impl WinConsole {
     /// use win32console::console::{WinConsole, ConsoleTextAttribute};
     /// let info = WinConsole::output().get_screen_buffer_info().unwrap();
     /// ```
+    #[inline]
     pub fn get_screen_buffer_info(&self) -> Result<ConsoleScreenBufferInfo> {
-        let handle = self.get_handle();
-
-        unsafe {
-            let mut info: CONSOLE_SCREEN_BUFFER_INFO = std::mem::zeroed();
-            if GetConsoleScreenBufferInfo(**handle, &mut info) != 0 {
-                Ok(ConsoleScreenBufferInfo::from(info))
-            } else {
-                Err(Error::last_os_error())
-            }
-        }
+        WinConsole::screen_buffer_info_for(self.get_handle())
     }
 
     /// Gets extended information of the console screen buffer.
@@ -1467,78 +2877,163 @@ impl WinConsole {
         }
     }
 
-    /// Sets the position of the cursor. don't confuse with mouse cursor.
-    ///
-    /// Wraps a call to [SetConsoleCursorPosition](https://docs.microsoft.com/en-us/windows/console/setconsolecursorposition).
+    /// Gets the size of the console screen buffer,
+    /// where `x` is the number of columns and `y` the number of rows.
     ///
     /// # Errors
     /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
-    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// use win32console::structs::input_record::InputRecord::KeyEvent;
-    ///
-    /// fn remove_last(){
-    ///        let pos = WinConsole::output().get_cursor_position().unwrap();
-    ///        if pos.x > 0{
-    ///            const WHITE_SPACE : &[u8; 1] = b" ";
-    ///            let new_pos = pos.with_x(pos.x - 1);
-    ///
-    ///            // Move back, write a whitespace and move back again
-    ///            // to remove the last written char
-    ///            WinConsole::output().set_cursor_position(new_pos);
-    ///            WinConsole::output().write_utf8(WHITE_SPACE);
-    ///            WinConsole::output().set_cursor_position(new_pos);
-    ///        }
-    ///    }
-    ///
-    ///    // A simple alphanumeric reader from the std input
-    ///    loop{
-    ///        if let KeyEvent(event) = WinConsole::input().read_single_input().unwrap(){
-    ///             // Only enter when the key is pressed down
-    ///            if event.key_down{
-    ///                // Only alphanumeric are allowed so any other is ignore
-    ///                if event.u_char.is_ascii_alphanumeric() {
-    ///                    let mut buf = [0];
-    ///                    event.u_char.encode_utf8(&mut buf);
-    ///                    // Write the character
-    ///                    WinConsole::output().write_utf8(&buf);
-    ///                }
-    ///                else{
-    ///                    match event.virtual_key_code{
-    ///                        0x08 => { remove_last(); } // Remove last on backspace press
-    ///                        0x1B => { break; }         // Exit when escape is press
-    ///                        _ => {}
-    ///                    }
-    ///                }
-    ///            }
-    ///        }
-    ///    }
+    /// let size = WinConsole::output().get_screen_buffer_size().unwrap();
+    /// WinConsole::output().write_utf8(format!("Buffer columns: {}, rows: {}", size.x, size.y).as_bytes()).unwrap();
     /// ```
-    pub fn set_cursor_position(&self, coord: Coord) -> Result<()> {
-        unsafe {
-            let handle = self.get_handle();
-            if SetConsoleCursorPosition(**handle, coord.into()) != 0 {
-                Ok(())
-            } else {
-                Err(Error::last_os_error())
-            }
-        }
+    #[inline]
+    pub fn get_screen_buffer_size(&self) -> Result<Coord> {
+        self.get_screen_buffer_info()
+            .map(|value| value.screen_buffer_size)
     }
 
-    /// Gets the current position of the cursor. don't confuse with mouse cursor.
+    /// Gets the rect of the visible window of the console screen buffer.
     ///
     /// # Errors
     /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
-    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// use win32console::structs::input_record::InputRecord::KeyEvent;
-    ///
+    /// let window = WinConsole::output().get_window_rect().unwrap();
+    /// WinConsole::output().write_utf8(format!("Window: {}", window).as_bytes()).unwrap();
+    /// ```
+    #[inline]
+    pub fn get_window_rect(&self) -> Result<SmallRect> {
+        self.get_screen_buffer_info()
+            .map(|value| value.window)
+    }
+
+    /// Gets the size of the visible window of the console screen buffer,
+    /// where `x` is the number of columns and `y` the number of rows.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// let size = WinConsole::output().get_window_size().unwrap();
+    /// WinConsole::output().write_utf8(format!("Columns: {}, Rows: {}", size.x, size.y).as_bytes()).unwrap();
+    /// ```
+    pub fn get_window_size(&self) -> Result<Coord> {
+        let window = self.get_window_rect()?;
+        Ok(Coord::new(window.width(), window.height()))
+    }
+
+    /// Sets the size of the visible window of the console screen buffer,
+    /// where `x` is the number of columns and `y` the number of rows.
+    ///
+    /// The window can't be larger than the screen buffer, so if the requested size is larger than
+    /// the current screen buffer, the screen buffer is enlarged first by calling
+    /// [`set_screen_buffer_size`] and then the window is set using [`set_window_info`].
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - If `size` is zero or negative.
+    /// - If `size` is larger than the largest window size, see [`get_largest_window_size`].
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::coord::Coord;
+    ///
+    /// WinConsole::output().set_window_size(Coord::new(60, 20)).unwrap();
+    /// assert_eq!(Coord::new(60, 20), WinConsole::output().get_window_size().unwrap());
+    /// ```
+    ///
+    /// [`set_screen_buffer_size`]: #method.set_screen_buffer_size
+    /// [`set_window_info`]: #method.set_window_info
+    /// [`get_largest_window_size`]: #method.get_largest_window_size
+    pub fn set_window_size(&self, size: Coord) -> Result<()> {
+        if size.x <= 0 || size.y <= 0 {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("Invalid window size: {}", size)));
+        }
+
+        let buffer_size = self.get_screen_buffer_info()?.screen_buffer_size;
+
+        // The screen buffer must be at least as large as the window
+        if size.x > buffer_size.x || size.y > buffer_size.y {
+            let new_buffer_size = Coord::new(buffer_size.x.max(size.x), buffer_size.y.max(size.y));
+            self.set_screen_buffer_size(new_buffer_size)?;
+        }
+
+        let window = SmallRect::new(0, 0, size.x - 1, size.y - 1);
+        self.set_window_info(true, &window)
+    }
+
+    /// Resizes both the screen buffer and the visible window to the given number of columns and rows.
+    ///
+    /// The screen buffer can't be smaller than the window and the window can't be larger than
+    /// the screen buffer, so when shrinking the window is resized first and then the screen buffer,
+    /// and when growing the screen buffer is resized first and then the window.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - If `cols` or `rows` are zero or negative.
+    /// - If the size is larger than the largest window size, see [`get_largest_window_size`].
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::coord::Coord;
+    ///
+    /// // Grow
+    /// WinConsole::output().resize(100, 40).unwrap();
+    /// assert_eq!(Coord::new(100, 40), WinConsole::output().get_window_size().unwrap());
+    /// assert_eq!(Coord::new(100, 40), WinConsole::output().get_screen_buffer_info().unwrap().screen_buffer_size);
+    ///
+    /// // Shrink
+    /// WinConsole::output().resize(60, 20).unwrap();
+    /// assert_eq!(Coord::new(60, 20), WinConsole::output().get_window_size().unwrap());
+    /// assert_eq!(Coord::new(60, 20), WinConsole::output().get_screen_buffer_info().unwrap().screen_buffer_size);
+    /// ```
+    ///
+    /// [`get_largest_window_size`]: #method.get_largest_window_size
+    pub fn resize(&self, cols: i16, rows: i16) -> Result<()> {
+        if cols <= 0 || rows <= 0 {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("Invalid console size: ({}, {})", cols, rows)));
+        }
+
+        let window = self.get_window_rect()?;
+
+        // Shrinks the window first, so fits in the new screen buffer
+        if window.width() > cols || window.height() > rows {
+            let width = window.width().min(cols);
+            let height = window.height().min(rows);
+            self.set_window_info(true, &SmallRect::new(0, 0, width - 1, height - 1))?;
+        }
+
+        self.set_screen_buffer_size(Coord::new(cols, rows))?;
+        self.set_window_info(true, &SmallRect::new(0, 0, cols - 1, rows - 1))
+    }
+
+    /// Sets the position of the cursor. don't confuse with mouse cursor.
+    ///
+    /// Wraps a call to [SetConsoleCursorPosition](https://docs.microsoft.com/en-us/windows/console/setconsolecursorposition).
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::input_record::InputRecord::KeyEvent;
+    ///
     /// fn remove_last(){
     ///        let pos = WinConsole::output().get_cursor_position().unwrap();
     ///        if pos.x > 0{
@@ -1560,10 +3055,8 @@ impl WinConsole {
     ///            if event.key_down{
     ///                // Only alphanumeric are allowed so any other is ignore
     ///                if event.u_char.is_ascii_alphanumeric() {
-    ///                    let mut buf = [0];
-    ///                    event.u_char.encode_utf8(&mut buf);
     ///                    // Write the character
-    ///                    WinConsole::output().write_utf8(&buf);
+    ///                    WinConsole::output().write_char(event.u_char);
     ///                }
     ///                else{
     ///                    match event.virtual_key_code{
@@ -1576,187 +3069,190 @@ impl WinConsole {
     ///        }
     ///    }
     /// ```
-    #[inline]
-    pub fn get_cursor_position(&self) -> Result<Coord> {
-        self.get_screen_buffer_info()
-            .map(|value| value.cursor_position)
+    pub fn set_cursor_position(&self, coord: Coord) -> Result<()> {
+        unsafe {
+            let handle = self.get_handle();
+            if SetConsoleCursorPosition(**handle, coord.into()) != 0 {
+                Ok(())
+            } else {
+                Err(Error::last_os_error())
+            }
+        }
     }
 
-    /// Retrieves information about the size and visibility of the cursor for the specified console screen buffer.
-    ///
-    /// Wraps a call to [GetConsoleCursorInfo](https://docs.microsoft.com/en-us/windows/console/getconsolecursorinfo).
+    /// Sets the position of the cursor to the given column `x` and row `y`,
+    /// the same as calling `set_cursor_position(Coord::new(x, y))`.
     ///
     /// # Errors
     /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
-    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - If the position is outside the console screen buffer.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// let cursor_info = WinConsole::output().get_cursor_info();
+    /// use win32console::structs::coord::Coord;
+    ///
+    /// WinConsole::output().set_cursor_position_xy(5, 2).unwrap();
+    /// assert_eq!(Coord::new(5, 2), WinConsole::output().get_cursor_position().unwrap());
     /// ```
-    pub fn get_cursor_info(&self) -> Result<ConsoleCursorInfo>{
-        let handle = self.get_handle();
-        unsafe{
-            let mut info : CONSOLE_CURSOR_INFO = std::mem::zeroed();
-
-            if GetConsoleCursorInfo(**handle, &mut info) == 0{
-                Err(Error::last_os_error())
-            }
-            else{
-                Ok(ConsoleCursorInfo::from(info))
-            }
-        }
+    #[inline]
+    pub fn set_cursor_position_xy(&self, x: i16, y: i16) -> Result<()> {
+        self.set_cursor_position(Coord::new(x, y))
     }
 
-    /// Clears the content of the console screen buffer and set the cursor to (0, 0)
+    /// Moves the cursor the given number of columns `dx` and rows `dy` from its current position,
+    /// the new position is clamped to the bounds of the console screen buffer.
     ///
     /// # Errors
-    /// - No documented errors.
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// WinConsole::output().clear();
+    /// use win32console::structs::coord::Coord;
+    ///
+    /// WinConsole::output().set_cursor_position_xy(5, 2).unwrap();
+    /// WinConsole::output().move_cursor_by(3, -1).unwrap();
+    /// assert_eq!(Coord::new(8, 1), WinConsole::output().get_cursor_position().unwrap());
+    ///
+    /// // The cursor don't move outside the screen buffer
+    /// WinConsole::output().move_cursor_by(-100, -100).unwrap();
+    /// assert_eq!(Coord::ZERO, WinConsole::output().get_cursor_position().unwrap());
     /// ```
-    pub fn clear(&self) -> Result<()> {
-        // Gets the size of the current screen buffer
+    pub fn move_cursor_by(&self, dx: i16, dy: i16) -> Result<()> {
         let info = self.get_screen_buffer_info()?;
         let size = info.screen_buffer_size;
-        let length: u32 = size.x as u32 * size.y as u32;
-
-        // Fills the console with a whitespace
-        self.fill_with_char(Coord::default(), length, ' ')?;
-
-        // Fills with the current attribute.
-        self.fill_with_attribute(Coord::default(), length, info.attributes)?;
+        let bounds = SmallRect::new(0, 0, size.x - 1, size.y - 1);
 
-        // Set the cursor position to (0, 0)
-        self.set_cursor_position(Coord::default())?;
+        let position = Coord::new(
+            info.cursor_position.x.saturating_add(dx),
+            info.cursor_position.y.saturating_add(dy)
+        );
 
-        Ok(())
+        self.set_cursor_position(position.clamp_to(bounds))
     }
 
-    /// Fills the content of the console with the specified [`char`].
-    ///
-    /// Wraps a call to [FillConsoleOutputCharacterW](https://docs.microsoft.com/en-us/windows/console/fillconsoleoutputcharacter).
+    /// Moves the cursor to the top-left corner of the console screen buffer (0, 0).
     ///
     /// # Errors
     /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
-    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// let current_pos = WinConsole::output().get_cursor_position().unwrap();
-    /// WinConsole::output().fill_with_char(current_pos, 10, 'x').unwrap();
+    /// use win32console::structs::coord::Coord;
+    ///
+    /// WinConsole::output().write_utf8(b"Hello World!").unwrap();
+    /// WinConsole::output().move_cursor_home().unwrap();
+    /// assert_eq!(Coord::ZERO, WinConsole::output().get_cursor_position().unwrap());
     /// ```
-    pub fn fill_with_char(
-        &self,
-        start_location: Coord,
-        cells_to_write: u32,
-        value: char,
-    ) -> Result<u32> {
-        let handle = self.get_handle();
-        let mut chars_written = 0;
-
-        unsafe {
-            if FillConsoleOutputCharacterW(
-                **handle,
-                value as u16,
-                cells_to_write,
-                start_location.into(),
-                &mut chars_written,
-            ) == 0
-            {
-                Err(Error::last_os_error())
-            } else {
-                Ok(chars_written)
-            }
-        }
+    #[inline]
+    pub fn move_cursor_home(&self) -> Result<()> {
+        self.set_cursor_position(Coord::ZERO)
     }
 
-    /// Fills the content of the console with the specified attribute.
+    /// Moves the cursor to the first column of the next row without write a newline character,
+    /// if the cursor is in the last row of the console screen buffer the content is scrolled up by one row.
     ///
-    /// Wraps a call to [FillConsoleOutputAttribute](https://docs.microsoft.com/en-us/windows/console/fillconsoleoutputattribute).
+    /// # Remarks
+    /// - The cursor is moved directly so this is not affected by the output modes that changes how
+    ///   the newlines are written, like `ENABLE_WRAP_AT_EOL_OUTPUT` or `DISABLE_NEWLINE_AUTO_RETURN`.
     ///
     /// # Errors
     /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
-    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// let len = 100;
-    /// let current_pos = WinConsole::output().get_cursor_position().unwrap();
-    /// WinConsole::output().fill_with_char(current_pos, len, ' ').unwrap();
     ///
-    /// for i in 0..len{
-    ///    let mut pos = current_pos.clone();
-    ///    pos.x += i as i16;
-    ///    let color : u16 = (16 << (i % 3)) as u16; // Apply colors to the characters
-    ///    WinConsole::output().fill_with_attribute(pos, 1, color);
-    ///}
+    /// WinConsole::output().write_utf8(b"Hello").unwrap();
+    /// let row = WinConsole::output().get_cursor_position().unwrap().y;
+    /// WinConsole::output().new_line().unwrap();
+    ///
+    /// let position = WinConsole::output().get_cursor_position().unwrap();
+    /// assert_eq!(0, position.x);
+    /// assert!(position.y == row + 1 || position.y == row);
     /// ```
-    pub fn fill_with_attribute(
-        &self,
-        start_location: Coord,
-        cells_to_write: u32,
-        attribute: u16,
-    ) -> Result<u32> {
-        let handle = self.get_handle();
-        let mut att_written = 0;
+    pub fn new_line(&self) -> Result<()> {
+        let info = self.get_screen_buffer_info()?;
+        let size = info.screen_buffer_size;
+        let row = info.cursor_position.y;
 
-        unsafe {
-            if FillConsoleOutputAttribute(
-                **handle,
-                attribute,
-                cells_to_write,
-                start_location.into(),
-                &mut att_written,
-            ) == 0
-            {
-                Err(Error::last_os_error())
-            } else {
-                Ok(att_written)
-            }
+        if row < size.y - 1 {
+            return self.set_cursor_position(Coord::new(0, row + 1));
         }
+
+        // Scrolls the content of the screen buffer up by one row
+        let scroll_rect = SmallRect::new(0, 1, size.x - 1, size.y - 1);
+        self.scroll_screen_buffer(scroll_rect, None, Coord::ZERO, CharInfo::new(' ', info.attributes))?;
+        self.set_cursor_position(Coord::new(0, size.y - 1))
     }
 
-    /// Sets the text attribute of the characters in the console.
-    ///
-    /// - `attribute`: the attributes to use, those attributes can be access using `ConsoleTextAttribute` struct.
+    /// Writes the specified text at the start of the current row, clears the remaining characters
+    /// of the row and moves the cursor back to the start of the row, so the next call overwrites the same row.
     ///
-    /// Wraps a call to [SetConsoleTextAttribute](https://docs.microsoft.com/en-us/windows/console/setconsoletextattribute).
+    /// This is useful to display progress bars or spinners, the cursor is moved directly instead of
+    /// writing a carriage return `\r`, if the output is being redirected the text is written followed by a newline.
     ///
     /// # Errors
     /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
-    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
-    /// use win32console::console::{WinConsole, ConsoleTextAttribute};
-    ///
-    /// let old_attributes = WinConsole::output().get_text_attribute().unwrap();
-    /// let new_attributes = ConsoleTextAttribute::BACKGROUND_BLUE;
-    ///
-    /// WinConsole::output().set_text_attribute(new_attributes);
-    /// WinConsole::output().write_utf8("Hello World!".as_bytes());
-    /// WinConsole::output().set_text_attribute(old_attributes);
-    /// ```
-    pub fn set_text_attribute(&self, attribute: u16) -> Result<()> {
-        let handle = self.get_handle();
-
-        unsafe {
-            if SetConsoleTextAttribute(**handle, attribute) != 0 {
-                Ok(())
-            } else {
-                Err(Error::last_os_error())
-            }
+    /// use std::fs::File;
+    /// use std::os::windows::io::AsRawHandle;
+    /// use win32console::console::{WinConsole, HandleType};
+    /// use win32console::structs::handle::Handle;
+    ///
+    /// // Overwrites the current row of the console
+    /// WinConsole::output().clear().unwrap();
+    /// WinConsole::output().overwrite_line("Downloading... 10%").unwrap();
+    /// WinConsole::output().overwrite_line("Done").unwrap();
+    ///
+    /// assert_eq!(0, WinConsole::output().get_cursor_position().unwrap().x);
+    /// assert!(WinConsole::output().snapshot_text().unwrap().starts_with("Done\n"));
+    ///
+    /// // Writes each line when the output is redirected
+    /// let path = std::env::temp_dir().join("overwrite_line.txt");
+    /// let std_output = WinConsole::get_std_handle(HandleType::Output).unwrap();
+    /// let file = File::create(&path).unwrap();
+    /// WinConsole::set_std_handle(HandleType::Output, Handle::new(file.as_raw_handle() as _)).unwrap();
+    /// WinConsole::output().overwrite_line("Downloading... 10%").unwrap();
+    /// WinConsole::output().overwrite_line("Done").unwrap();
+    /// WinConsole::set_std_handle(HandleType::Output, std_output).unwrap();
+    ///
+    /// assert_eq!("Downloading... 10%\nDone\n", std::fs::read_to_string(&path).unwrap());
+    ///
+    /// drop(file);
+    /// std::fs::remove_file(path).unwrap();
+    /// ```
+    pub fn overwrite_line(&self, text: &str) -> Result<()> {
+        if !WinConsole::is_console(self.get_handle()) {
+            self.write_all_utf8(text.as_bytes())?;
+            self.write_all_utf8(b"\n")?;
+            return Ok(());
         }
+
+        let info = self.get_screen_buffer_info()?;
+        let row = info.cursor_position.y;
+
+        self.set_cursor_position(Coord::new(0, row))?;
+        self.write_utf8(text.as_bytes())?;
+
+        // Clears the rest of the row
+        let position = self.get_cursor_position()?;
+        let remaining = (info.screen_buffer_size.x - position.x).max(0) as u32;
+        self.fill_with_char(position, remaining, ' ')?;
+
+        self.set_cursor_position(Coord::new(0, row))
     }
 
-    /// Gets the text attributes of the characters in the console.
+    /// Gets the current position of the cursor. don't confuse with mouse cursor.
     ///
     /// # Errors
     /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
@@ -1764,128 +3260,103 @@ impl WinConsole {
     ///
     /// # Example
     /// ```
-    /// use win32console::console::{WinConsole, ConsoleTextAttribute};
-    ///
-    /// let old_attributes = WinConsole::output().get_text_attribute().unwrap();
-    /// let new_attributes = ConsoleTextAttribute::BACKGROUND_BLUE;
-    ///
-    /// WinConsole::output().set_text_attribute(new_attributes);
-    /// WinConsole::output().write_utf8("Hello World!".as_bytes());
-    /// WinConsole::output().set_text_attribute(old_attributes);
-    /// ```
-    #[inline]
-    pub fn get_text_attribute(&self) -> Result<u16> {
-        Ok(self.get_screen_buffer_info()?.attributes)
-    }
-
-    /// Gets the largest size the console window can get.
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::input_record::InputRecord::KeyEvent;
     ///
-    /// Wraps a call to [GetLargestConsoleWindowSize](https://docs.microsoft.com/en-us/windows/console/getlargestconsolewindowsize).
+    /// fn remove_last(){
+    ///        let pos = WinConsole::output().get_cursor_position().unwrap();
+    ///        if pos.x > 0{
+    ///            const WHITE_SPACE : &[u8; 1] = b" ";
+    ///            let new_pos = pos.with_x(pos.x - 1);
     ///
-    /// # Errors
-    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
-    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    ///            // Move back, write a whitespace and move back again
+    ///            // to remove the last written char
+    ///            WinConsole::output().set_cursor_position(new_pos);
+    ///            WinConsole::output().write_utf8(WHITE_SPACE);
+    ///            WinConsole::output().set_cursor_position(new_pos);
+    ///        }
+    ///    }
     ///
-    /// # Example
-    /// ```
-    /// use win32console::console::WinConsole;
-    /// let max_size = WinConsole::output().get_largest_window_size().unwrap();
+    ///    // A simple alphanumeric reader from the std input
+    ///    loop{
+    ///        if let KeyEvent(event) = WinConsole::input().read_single_input().unwrap(){
+    ///             // Only enter when the key is pressed down
+    ///            if event.key_down{
+    ///                // Only alphanumeric are allowed so any other is ignore
+    ///                if event.u_char.is_ascii_alphanumeric() {
+    ///                    // Write the character
+    ///                    WinConsole::output().write_char(event.u_char);
+    ///                }
+    ///                else{
+    ///                    match event.virtual_key_code{
+    ///                        0x08 => { remove_last(); } // Remove last on backspace press
+    ///                        0x1B => { break; }         // Exit when escape is press
+    ///                        _ => {}
+    ///                    }
+    ///                }
+    ///            }
+    ///        }
+    ///    }
     /// ```
-    pub fn get_largest_window_size(&self) -> Result<Coord> {
-        let handle = self.get_handle();
-
-        unsafe {
-            let coord: Coord = GetLargestConsoleWindowSize(**handle).into();
-
-            if coord == Coord::ZERO {
-                Err(Error::last_os_error())
-            } else {
-                Ok(coord)
-            }
-        }
+    #[inline]
+    pub fn get_cursor_position(&self) -> Result<Coord> {
+        self.get_screen_buffer_info()
+            .map(|value| value.cursor_position)
     }
 
-    /// Gets the number of unread input events.
+    /// Retrieves information about the size and visibility of the cursor for the specified console screen buffer.
     ///
-    /// Wraps a call to [GetNumberOfConsoleInputEvents](https://docs.microsoft.com/en-us/windows/console/getnumberofconsoleinputevents).
+    /// Wraps a call to [GetConsoleCursorInfo](https://docs.microsoft.com/en-us/windows/console/getconsolecursorinfo).
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
-    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    /// the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// let unread_events = WinConsole::input().get_number_of_input_events().unwrap();
+    /// let cursor_info = WinConsole::output().get_cursor_info();
     /// ```
-    pub fn get_number_of_input_events(&self) -> Result<usize> {
+    pub fn get_cursor_info(&self) -> Result<ConsoleCursorInfo>{
         let handle = self.get_handle();
+        unsafe{
+            let mut info : CONSOLE_CURSOR_INFO = std::mem::zeroed();
 
-        unsafe {
-            let mut num_events = 0;
-            if GetNumberOfConsoleInputEvents(**handle, &mut num_events) == 0 {
+            if GetConsoleCursorInfo(**handle, &mut info) == 0{
                 Err(Error::last_os_error())
-            } else {
-                Ok(num_events as usize)
+            }
+            else{
+                Ok(ConsoleCursorInfo::from(info))
             }
         }
     }
 
-    /// Gets the number of mouse buttons used for the mouse available for this console.
+    /// Sets the size and visibility of the cursor for the specified console screen buffer.
     ///
-    /// Wraps a call to [GetNumberOfConsoleMouseButtons](https://docs.microsoft.com/en-us/windows/console/getnumberofconsolemousebuttons).
+    /// Wraps a call to [SetConsoleCursorInfo](https://docs.microsoft.com/en-us/windows/console/setconsolecursorinfo).
     ///
     /// # Errors
-    /// - No documented errors.
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - If the `size` of the cursor is not between 1 and 100.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// let x = WinConsole::input().get_number_of_mouse_buttons().unwrap();
-    /// let y = WinConsole::output().get_number_of_mouse_buttons().unwrap();
-    /// assert_eq!(x, y);
-    /// ```
-    pub fn get_number_of_mouse_buttons(&self) -> Result<u32> {
-        let mut num_buttons = 0;
-
-        unsafe {
-            if GetNumberOfConsoleMouseButtons(&mut num_buttons) == 0 {
-                Err(Error::last_os_error())
-            } else {
-                Ok(num_buttons)
-            }
-        }
-    }
-
-    /// Moves a block of data in a screen buffer.
-    /// The effects of the move can be limited by specifying a clipping rectangle,
-    /// so the contents of the console screen buffer outside the clipping rectangle are unchanged.
+    /// use win32console::structs::console_cursor_info::ConsoleCursorInfo;
     ///
-    /// Wraps a call to [ScrollConsoleScreenBufferW](https://docs.microsoft.com/en-us/windows/console/scrollconsolescreenbuffer).
+    /// let old_info = WinConsole::output().get_cursor_info().unwrap();
+    /// WinConsole::output().set_cursor_info(ConsoleCursorInfo{ size: 100, visible: true }).unwrap();
+    /// assert_eq!(100, WinConsole::output().get_cursor_info().unwrap().size);
     ///
-     /// # Errors
-    /// - No documented errors.
-    pub fn scroll_screen_buffer(&self,
-                                scroll_rect: SmallRect,
-                                clip_rect: Option<SmallRect>,
-                                destination: Coord,
-                                fill: CharInfo
-    ) -> Result<()>{
+    /// WinConsole::output().set_cursor_info(old_info).unwrap();
+    /// ```
+    pub fn set_cursor_info(&self, info: ConsoleCursorInfo) -> Result<()>{
         let handle = self.get_handle();
-        let chi = &mut fill.into();
-        let srect = &mut scroll_rect.into();
-        let crect = match clip_rect{
-            Some(r) => &mut r.into(),
-            None => null_mut()
-        };
+        let info : CONSOLE_CURSOR_INFO = info.into();
 
         unsafe{
-            if ScrollConsoleScreenBufferW(
-                **handle,
-                srect,
-                crect,
-                destination.into(),
-                chi) == 0{
+            if SetConsoleCursorInfo(**handle, &info) == 0{
                 Err(Error::last_os_error())
             }
             else{
@@ -1894,864 +3365,725 @@ impl WinConsole {
         }
     }
 
-    /// Reads a single event from the console.
+    /// Checks if the cursor of the console screen buffer is visible.
+    ///
+    /// Wraps a call to [GetConsoleCursorInfo](https://docs.microsoft.com/en-us/windows/console/getconsolecursorinfo).
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
-    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
-    /// use win32console::structs::input_record::InputRecord::KeyEvent;
     /// use win32console::console::WinConsole;
+    /// use win32console::structs::console_cursor_info::ConsoleCursorInfo;
     ///
-    /// loop{
-    ///        // A simple alphanumeric reader from the std input
-    ///        if let KeyEvent(event) = WinConsole::input().read_single_input().unwrap(){
-    ///             // Only enter when the key is pressed down
-    ///            if event.key_down{
-    ///                // Only alphanumeric are allowed so any other is ignore
-    ///                if !(event.u_char.is_ascii_alphanumeric()) {
-    ///                    match event.virtual_key_code{
-    ///                        0x1B => { break; }         // Exit when escape is press
-    ///                        _ => {}
-    ///                    }
-    ///                }
-    ///                 else {
-    ///                    let mut buf = [0];
-    ///                    event.u_char.encode_utf8(&mut buf);
-    ///                    // Write the character
-    ///                    WinConsole::output().write_utf8(&buf);
-    ///                 }
-    ///            }
-    ///        }
-    ///    }
+    /// let old_info = WinConsole::output().get_cursor_info().unwrap();
+    /// WinConsole::output().set_cursor_info(ConsoleCursorInfo{ size: old_info.size, visible: false }).unwrap();
+    /// assert!(!WinConsole::output().is_cursor_visible().unwrap());
+    ///
+    /// WinConsole::output().set_cursor_info(old_info).unwrap();
     /// ```
-    pub fn read_single_input(&self) -> Result<InputRecord> {
-        unsafe {
-            let mut record: InputRecord = std::mem::zeroed();
-            let mut buf = slice::from_mut(&mut record);
-            self.read_input(&mut buf)?;
-            Ok(record)
-        }
+    #[inline]
+    pub fn is_cursor_visible(&self) -> Result<bool>{
+        self.get_cursor_info()
+            .map(|value| value.visible)
     }
 
-    /// Reads input events from the console.
+    /// Clears the content of the console screen buffer and set the cursor to (0, 0)
     ///
-    /// - `buffer_size`: the size of the buffer that will store the events.
+    /// # Errors
+    /// - No documented errors.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// WinConsole::output().clear();
+    /// ```
+    pub fn clear(&self) -> Result<()> {
+        // Gets the size of the current screen buffer
+        let info = self.get_screen_buffer_info()?;
+        let size = info.screen_buffer_size;
+        let length: u32 = size.x as u32 * size.y as u32;
+
+        // Fills the console with a whitespace
+        self.fill_with_char(Coord::default(), length, ' ')?;
+
+        // Fills with the current attribute.
+        self.fill_with_attribute(Coord::default(), length, info.attributes)?;
+
+        // Set the cursor position to (0, 0)
+        self.set_cursor_position(Coord::default())?;
+
+        Ok(())
+    }
+
+    /// Restores the console to its default state, this:
+    /// - Sets the text attribute to `FOREGROUND_RED | FOREGROUND_GREEN | FOREGROUND_BLUE` (`0x7`), which is gray text on a black background.
+    /// - Makes the cursor visible, the size of the cursor is not changed.
+    /// - If `clear` is `true`, clears the content of the console screen buffer and set the cursor to (0, 0), see [`clear`],
+    ///   otherwise the content and the cursor position are not changed.
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
-    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// use win32console::structs::input_record::InputRecord::KeyEvent;
-    /// let input_records = WinConsole::input().read_input_n(10).unwrap();
+    /// use win32console::structs::console_color::ConsoleColor;
     ///
-    /// let mut buf = String::new();
-    /// for record in input_records{
-    ///     if let KeyEvent(key) = record{
-    ///         if key.key_down && key.u_char.is_ascii_alphanumeric(){
-    ///             buf.push(key.u_char);
-    ///         }
-    ///     }
-    /// }
+    /// WinConsole::output().set_foreground_color(ConsoleColor::Red).unwrap();
+    /// WinConsole::output().write_utf8(b"Something went wrong!").unwrap();
     ///
-    /// WinConsole::output().write_utf8(buf.as_bytes());
+    /// WinConsole::output().reset(true).unwrap();
+    /// assert_eq!(0x7, WinConsole::output().get_text_attribute().unwrap());
+    /// assert!(WinConsole::output().get_cursor_info().unwrap().visible);
     /// ```
-    pub fn read_input_n(&self, buffer_size: usize) -> Result<Vec<InputRecord>> {
-        if buffer_size == 0 {
-            return Ok(vec![]);
-        }
+    ///
+    /// [`clear`]: #method.clear
+    pub fn reset(&self, clear: bool) -> Result<()> {
+        self.set_text_attribute(ConsoleTextAttribute::FOREGROUND_RED
+            | ConsoleTextAttribute::FOREGROUND_GREEN
+            | ConsoleTextAttribute::FOREGROUND_BLUE)?;
 
-        let mut buffer = vec![unsafe { std::mem::zeroed::<InputRecord>() }; buffer_size];
+        let mut cursor_info = self.get_cursor_info()?;
+        cursor_info.visible = true;
+        self.set_cursor_info(cursor_info)?;
 
-        self.read_input(buffer.as_mut_slice())?;
-        Ok(buffer)
+        if clear {
+            self.clear()?;
+        }
+
+        Ok(())
     }
 
-    /// Fills the specified buffer with [`InputRecord`] from the console.
-    ///
-    /// Wraps a call to [ReadConsoleInputW](https://docs.microsoft.com/en-us/windows/console/readconsoleinput).
+    /// Saves the content of the console screen buffer and the cursor position,
+    /// so can be restored later using [`restore_screen`].
     ///
-    /// # Returns
-    /// The number of input events read.
+    /// # Remarks
+    /// - The whole screen buffer is copied not only the visible window, a buffer of
+    ///   120x9001 cells takes more than 4MB of memory.
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
-    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
-    /// use std::mem::MaybeUninit;
-    /// use win32console::structs::input_record::InputRecord;
     /// use win32console::console::WinConsole;
-    /// use win32console::structs::input_record::InputRecord::KeyEvent;
+    /// use win32console::structs::coord::Coord;
     ///
-    /// let mut input_records : [InputRecord; 10] = unsafe { MaybeUninit::zeroed().assume_init() };
-    /// WinConsole::input().read_input(&mut input_records).unwrap();
+    /// WinConsole::output().write_utf8(b"Hello World!").unwrap();
+    /// let saved = WinConsole::output().save_screen().unwrap();
     ///
-    /// let mut buf = String::new();
-    /// for record in input_records.iter(){
-    ///     if let KeyEvent(key) = record{
-    ///         if key.key_down && key.u_char.is_ascii_alphanumeric(){
-    ///             buf.push(key.u_char);
-    ///         }
-    ///     }
-    /// }
+    /// // Take over the screen
+    /// WinConsole::output().clear().unwrap();
+    /// WinConsole::output().write_utf8(b"Goodbye World!").unwrap();
     ///
-    /// WinConsole::output().write_utf8(buf.as_bytes());
+    /// // Restore the previous content
+    /// WinConsole::output().restore_screen(&saved).unwrap();
+    /// assert_eq!(saved.get_cursor_position(), WinConsole::output().get_cursor_position().unwrap());
+    /// assert_eq!(saved, WinConsole::output().save_screen().unwrap());
     /// ```
-    pub fn read_input(&self, records: &mut [InputRecord]) -> Result<usize> {
-        if records.len() == 0 {
-            return Ok(0);
-        }
-
-        let handle = self.get_handle();
-        let num_records = records.len();
-        let mut num_events = 0;
-
-        let mut buf = vec![unsafe { std::mem::zeroed::<INPUT_RECORD>() }; num_records];
-
-        unsafe {
-            if ReadConsoleInputW(
-                **handle,
-                buf.as_mut_ptr(),
-                num_records as u32,
-                &mut num_events,
-            ) == 0
-            {
-                Err(Error::last_os_error())
-            } else {
-                // Documentation specify that at least 1 event will be read.
-                debug_assert!(num_events > 0);
-
-                // Copies each of the read events to the destination buffer
-                for i in 0..num_records {
-                    records[i] = buf[i].into()
-                }
+    ///
+    /// [`restore_screen`]: #method.restore_screen
+    pub fn save_screen(&self) -> Result<SavedScreen>{
+        let info = self.get_screen_buffer_info()?;
+        let buffer = self.read_full_buffer()?;
 
-                Ok(num_events as usize)
-            }
-        }
+        Ok(SavedScreen{
+            buffer,
+            size: info.screen_buffer_size,
+            cursor_position: info.cursor_position
+        })
     }
 
-    /// Reads character and color attribute data from a rectangular block of character cells in a console screen buffer,
-    /// and the function writes the data to a rectangular block at a specified location in the destination buffer.
+    /// Writes back the content of a screen buffer saved with [`save_screen`] and restores the
+    /// cursor position.
     ///
-    /// Wraps a call to [ReadConsoleOutputW](https://docs.microsoft.com/en-us/windows/console/readconsoleoutput).
-    pub fn read_output(&self, buffer_size: Coord, buffer_coord: Coord, read_region: &mut SmallRect) -> Result<Vec<CharInfo>>{
-        let handle = self.get_handle();
-        let length = buffer_size.x * buffer_size.y;
-        let mut buffer = vec![unsafe{ std::mem::zeroed::<CHAR_INFO>() }; length as usize];
-        let raw_rect = &mut (*read_region).into();
-
-        unsafe{
-            if ReadConsoleOutputW(
-                **handle,
-                buffer.as_mut_ptr(),
-                buffer_size.into(),
-                buffer_coord.into(),
-                raw_rect) == 0{
-                Err(Error::last_os_error())
-            }
-            else{
-                let ret = buffer.iter()
-                    .map(|c| (*c).into())
-                    .collect::<Vec<CharInfo>>();
-
-                *read_region = SmallRect::from(*raw_rect);
-                Ok(ret)
-            }
-        }
-    }
-
-    /// Copies a specified number of character attributes from consecutive cells of a console screen buffer, beginning at a specified location.
-///
-/// Wraps a call to [ReadConsoleOutputAttribute](https://docs.microsoft.com/en-us/windows/console/readconsoleoutputattribute).
-///
-/// # Returns
-/// The number of attributes read.
-///
-/// # Errors
-/// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
-/// the function should be called using `WinConsole::input()` or a valid input handle.
-///
-/// # Example
-/// ```
-/// use win32console::console::{WinConsole, ConsoleTextAttribute};
-/// use win32console::structs::console_color::ConsoleColor;
-/// use win32console::structs::coord::Coord;
-///
-/// fn write_color(data: &str, color: ConsoleColor){
-///     let c = WinConsole::output().get_foreground_color().unwrap();
-///     WinConsole::output().set_foreground_color(color);
-///     WinConsole::output().write_utf8(data.as_bytes());
-///     WinConsole::output().set_foreground_color(c);
-/// }
-///
-/// WinConsole::output().clear();
-/// write_color("R", ConsoleColor::DarkRed);
-/// write_color("G", ConsoleColor::DarkGreen);
-/// write_color("B", ConsoleColor::DarkBlue);
-///
-/// let mut buf = [0u16, 0u16, 0u16];
-/// let attributes_read = WinConsole::output().read_output_attribute(&mut buf, Coord::ZERO).unwrap();
-///
-/// assert_eq!(3, attributes_read);
-/// assert_eq!(ConsoleTextAttribute::FOREGROUND_RED, buf[0]);
-/// assert_eq!(ConsoleTextAttribute::FOREGROUND_GREEN, buf[1]);
-/// assert_eq!(ConsoleTextAttribute::FOREGROUND_BLUE, buf[2]);
-/// ```
-    pub fn read_output_attribute(&self, buffer: &mut [u16], read_coord: Coord) -> Result<usize>{
-        if buffer.len() == 0{
-            return Ok(0);
-        }
-
-        let handle = self.get_handle();
-
-        unsafe{
-            let mut attributes_read = 0;
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// let saved = WinConsole::output().save_screen().unwrap();
+    /// WinConsole::output().clear().unwrap();
+    /// WinConsole::output().write_utf8(b"Temporal content").unwrap();
+    /// WinConsole::output().restore_screen(&saved).unwrap();
+    /// ```
+    ///
+    /// [`save_screen`]: #method.save_screen
+    pub fn restore_screen(&self, saved: &SavedScreen) -> Result<()>{
+        let size = saved.size;
+        let write_area = SmallRect::new(0, 0, size.x - 1, size.y - 1);
 
-            if ReadConsoleOutputAttribute(**handle, buffer.as_mut_ptr(), buffer.len() as u32, read_coord.into(), &mut attributes_read) == 0{
-                Err(Error::last_os_error())
-            }
-            else{
-                Ok(attributes_read as usize)
-            }
-        }
+        self.write_output(&saved.buffer, size, Coord::ZERO, write_area)?;
+        self.set_cursor_position(saved.cursor_position)
     }
 
-    /// Copies a number of characters from consecutive cells of a console screen buffer, beginning at a specified location.
+    /// Loads a screen saved with [`save_screen`] from a `JSON` file and writes it to the screen buffer,
+    /// as [`restore_screen`] does, useful to present splash screens or console art.
     ///
-    /// Wraps a call to [ReadConsoleOutputCharacterW](https://docs.microsoft.com/en-us/windows/console/readconsoleoutputcharacter).
+    /// The file must contain the `serde` representation of a [`SavedScreen`], which can be written
+    /// using `serde_json::to_writer`.
     ///
-    /// # Returns
-    /// The number of characters read.
+    /// This method is only available with the `json` feature.
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
-    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - If the file cannot be read.
+    /// - `ErrorKind::InvalidData` if the content of the file is not a valid `SavedScreen`.
+    /// - `ErrorKind::InvalidInput` if the buffer have less cells than its size.
     ///
+    /// # Example
     /// ```
+    /// use std::fs::File;
     /// use win32console::console::WinConsole;
-    /// use win32console::structs::coord::Coord;
-    ///
-    /// WinConsole::output().clear();
     ///
-    /// let data = b"Hola Mundo";
-    /// WinConsole::output().write_utf8(data);
-    /// let mut buf = vec![u8::default(); data.len()];
-    /// let chars_read = WinConsole::output().read_output_character(&mut buf, Coord::ZERO).expect("Unable to read");
+    /// let path = std::env::temp_dir().join("splash.json");
+    /// let saved = WinConsole::output().save_screen().unwrap();
+    /// serde_json::to_writer(File::create(&path).unwrap(), &saved).unwrap();
     ///
-    /// assert_eq!(chars_read, data.len());
-    /// assert_eq!(data, buf.as_slice());
+    /// WinConsole::output().clear().unwrap();
+    /// WinConsole::output().load_and_present(&path).unwrap();
+    /// std::fs::remove_file(path).unwrap();
     /// ```
-    pub fn read_output_character(&self, buffer: &mut [u8], read_coord: Coord) -> Result<usize>{
-        if buffer.len() == 0{
-            return Ok(0);
-        }
+    ///
+    /// [`save_screen`]: #method.save_screen
+    /// [`restore_screen`]: #method.restore_screen
+    /// [`SavedScreen`]: struct.SavedScreen.html
+    #[cfg(feature = "json")]
+    pub fn load_and_present(&self, path: &std::path::Path) -> Result<()>{
+        let file = std::fs::File::open(path)?;
+        let saved: SavedScreen = serde_json::from_reader(std::io::BufReader::new(file))
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
 
-        let handle = self.get_handle();
+        self.restore_screen(&saved)
+    }
 
-        unsafe{
-            let mut chars_read = 0;
-            let mut utf16_buffer = vec![u16::default(); buffer.len()];
+    /// Fills the content of the console with the specified [`char`].
+    ///
+    /// Wraps a call to [FillConsoleOutputCharacterW](https://docs.microsoft.com/en-us/windows/console/fillconsoleoutputcharacter).
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// let current_pos = WinConsole::output().get_cursor_position().unwrap();
+    /// WinConsole::output().fill_with_char(current_pos, 10, 'x').unwrap();
+    /// ```
+    pub fn fill_with_char(
+        &self,
+        start_location: Coord,
+        cells_to_write: u32,
+        value: char,
+    ) -> Result<u32> {
+        let handle = self.get_handle();
+        let mut chars_written = 0;
 
-            if ReadConsoleOutputCharacterW(**handle, utf16_buffer.as_mut_ptr(), buffer.len() as u32, read_coord.into(), &mut chars_read) == 0{
+        unsafe {
+            if FillConsoleOutputCharacterW(
+                **handle,
+                value as u16,
+                cells_to_write,
+                start_location.into(),
+                &mut chars_written,
+            ) == 0
+            {
                 Err(Error::last_os_error())
-            }
-            else{
-                WinConsole::utf16_to_utf8(&utf16_buffer, buffer)?;
-                Ok(chars_read as usize)
+            } else {
+                Ok(chars_written)
             }
         }
     }
 
-    /// Fills the specified buffer with the unread [`InputRecord`] from the console.
+    /// Fills the content of the console with the specified attribute.
     ///
-    /// # Returns
-    /// The number of input events read.
+    /// Wraps a call to [FillConsoleOutputAttribute](https://docs.microsoft.com/en-us/windows/console/fillconsoleoutputattribute).
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
-    /// the function should be called using `WinConsole::input()` or a valid input handle.
-    ///
-    /// Wraps a call to [PeekConsoleInputW](https://docs.microsoft.com/en-us/windows/console/peekconsoleinput).
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    /// the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
-    /// use std::mem::MaybeUninit;
-    /// use win32console::structs::input_record::InputRecord;
     /// use win32console::console::WinConsole;
-    /// use win32console::structs::input_record::InputRecord::KeyEvent;
-    ///
-    /// let mut input_records : [InputRecord; 10] = unsafe { MaybeUninit::zeroed().assume_init() };
-    /// WinConsole::input().peek_input(&mut input_records).unwrap();
-    ///
-    /// let mut buf = String::new();
-    /// for record in input_records.iter(){
-    ///     if let KeyEvent(key) = record{
-    ///         if key.key_down && key.u_char.is_ascii_alphanumeric(){
-    ///             buf.push(key.u_char);
-    ///         }
-    ///     }
-    /// }
+    /// let len = 100;
+    /// let current_pos = WinConsole::output().get_cursor_position().unwrap();
+    /// WinConsole::output().fill_with_char(current_pos, len, ' ').unwrap();
     ///
-    /// WinConsole::output().write_utf8(buf.as_bytes());
+    /// for i in 0..len{
+    ///    let mut pos = current_pos.clone();
+    ///    pos.x += i as i16;
+    ///    let color : u16 = (16 << (i % 3)) as u16; // Apply colors to the characters
+    ///    WinConsole::output().fill_with_attribute(pos, 1, color);
+    ///}
     /// ```
-    pub fn peek_input(&self, records: &mut [InputRecord]) -> Result<usize> {
-        if records.len() == 0 {
-            return Ok(0);
-        }
-
+    pub fn fill_with_attribute(
+        &self,
+        start_location: Coord,
+        cells_to_write: u32,
+        attribute: u16,
+    ) -> Result<u32> {
         let handle = self.get_handle();
-        let num_records = records.len();
-        let mut num_events = 0;
+        let mut att_written = 0;
 
         unsafe {
-            let mut buf = iter::repeat_with(|| std::mem::zeroed::<INPUT_RECORD>())
-                .take(num_records)
-                .collect::<Vec<INPUT_RECORD>>();
-
-            if PeekConsoleInputW(
+            if FillConsoleOutputAttribute(
                 **handle,
-                buf.as_mut_ptr(),
-                num_records as u32,
-                &mut num_events,
+                attribute,
+                cells_to_write,
+                start_location.into(),
+                &mut att_written,
             ) == 0
             {
                 Err(Error::last_os_error())
             } else {
-                // Copies each of the read events to the destination buffer
-                for i in 0..num_records {
-                    records[i] = buf[i].into()
-                }
-
-                Ok(num_events as usize)
+                Ok(att_written)
             }
         }
     }
 
-    /// Reads a `String` from the standard input, followed by a newline.
+    /// Draws a horizontal line of the specified [`char`] and attribute, starting at `start` and
+    /// going to the right.
+    ///
+    /// - `start`: the position of the first cell of the line.
+    /// - `length`: the number of cells of the line.
+    /// - `c`: the character to draw in each cell.
+    /// - `attribute`: the attribute to set in each cell.
+    ///
+    /// Wraps a call to [FillConsoleOutputCharacterW](https://docs.microsoft.com/en-us/windows/console/fillconsoleoutputcharacter)
+    /// and [FillConsoleOutputAttribute](https://docs.microsoft.com/en-us/windows/console/fillconsoleoutputattribute).
+    ///
+    /// # Remarks
+    /// - If the line exceeds the width of the screen buffer it continues in the next row.
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
-    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
-    /// use win32console::console::WinConsole;
+    /// use win32console::console::{WinConsole, ConsoleTextAttribute};
+    /// use win32console::structs::coord::Coord;
     ///
-    /// WinConsole::output().write_utf8("What's your name? ".as_bytes());
-    /// let value = WinConsole::input().read_string().unwrap();
-    /// WinConsole::output().write_utf8(format!("Hello {}", value).as_bytes());
+    /// WinConsole::output().clear().unwrap();
+    /// WinConsole::output().draw_horizontal_line(Coord::new(2, 1), 5, '-', ConsoleTextAttribute::FOREGROUND_GREEN).unwrap();
+    ///
+    /// let mut buf = [0_u8; 5];
+    /// WinConsole::output().read_output_character(&mut buf, Coord::new(2, 1)).unwrap();
+    /// assert_eq!(b"-----", &buf);
     /// ```
-    pub fn read_string(&self) -> Result<String> {
-        // Used buffer size from:
-        // https://source.dot.net/#System.Console/System/Console.cs,dac049f8d10df4a0
-        const MAX_BUFFER_SIZE: usize = 4096;
-
-        let mut buffer: [u16; MAX_BUFFER_SIZE] = unsafe { MaybeUninit::zeroed().assume_init() };
-        let chars_read = self.read_utf16(&mut buffer)?;
-
-        match String::from_utf16(buffer[..chars_read].as_ref()) {
-            Ok(string) => Ok(string),
-            Err(e) => Err(Error::new(ErrorKind::InvalidData, e)),
-        }
+    pub fn draw_horizontal_line(&self, start: Coord, length: u32, c: char, attribute: u16) -> Result<()>{
+        self.fill_with_char(start, length, c)?;
+        self.fill_with_attribute(start, length, attribute)?;
+        Ok(())
     }
 
-    /// Fills the given `u8` buffer with characters from the standard input.
+    /// Draws a vertical line of the specified [`char`] and attribute, starting at `start` and
+    /// going down.
     ///
-    /// # Returns
-    /// The number of characters read.
+    /// - `start`: the position of the first cell of the line.
+    /// - `length`: the number of cells of the line.
+    /// - `c`: the character to draw in each cell.
+    /// - `attribute`: the attribute to set in each cell.
+    ///
+    /// Wraps a call to [FillConsoleOutputCharacterW](https://docs.microsoft.com/en-us/windows/console/fillconsoleoutputcharacter)
+    /// and [FillConsoleOutputAttribute](https://docs.microsoft.com/en-us/windows/console/fillconsoleoutputattribute)
+    /// for each cell of the line.
+    ///
+    /// # Remarks
+    /// - The line stops at the bottom of the screen buffer, the remaining cells are discarded.
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
-    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
-    /// use std::mem::MaybeUninit;
-    /// use win32console::console::WinConsole;
-    /// let mut buffer : [u8 ; 10] = unsafe { MaybeUninit::zeroed().assume_init() };
-    /// WinConsole::input().read_utf8(&mut buffer);
+    /// use win32console::console::{WinConsole, ConsoleTextAttribute};
+    /// use win32console::structs::coord::Coord;
+    ///
+    /// WinConsole::output().clear().unwrap();
+    /// WinConsole::output().draw_vertical_line(Coord::new(4, 0), 3, '|', ConsoleTextAttribute::FOREGROUND_RED).unwrap();
+    ///
+    /// for y in 0..3{
+    ///     let mut buf = [0_u8; 1];
+    ///     WinConsole::output().read_output_character(&mut buf, Coord::new(4, y)).unwrap();
+    ///     assert_eq!(b"|", &buf);
+    /// }
     /// ```
-    pub fn read_utf8(&self, buffer: &mut [u8]) -> Result<usize> {
-        if buffer.len() == 0 {
-            return Ok(0);
-        }
+    pub fn draw_vertical_line(&self, start: Coord, length: u32, c: char, attribute: u16) -> Result<()>{
+        let height = self.get_screen_buffer_size()?.y;
 
-        let mut utf16_buffer = vec![u16::default(); buffer.len()];
+        for y in (start.y..height).take(length as usize) {
+            let position = Coord::new(start.x, y);
+            self.fill_with_char(position, 1, c)?;
+            self.fill_with_attribute(position, 1, attribute)?;
+        }
 
-        // Writes the read data to the 'utf16_buffer'.
-        self.read_utf16(&mut utf16_buffer)?;
-        let written = WinConsole::utf16_to_utf8(&utf16_buffer, buffer)?;
-        Ok(written)
+        Ok(())
     }
 
-    /// Fills the given `u16` buffer with characters from the standard input.
+    /// Sets the text attribute of the characters in the console.
     ///
-    /// Wraps a call to [ReadConsoleW](https://docs.microsoft.com/en-us/windows/console/readconsole).
+    /// - `attribute`: the attributes to use, those attributes can be access using `ConsoleTextAttribute` struct.
     ///
-    /// # Returns
-    /// The number of characters read.
+    /// Wraps a call to [SetConsoleTextAttribute](https://docs.microsoft.com/en-us/windows/console/setconsoletextattribute).
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
-    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    /// the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
-    /// use std::mem::MaybeUninit;
-    /// use win32console::console::WinConsole;
-    /// let mut buffer : [u16 ; 10] = unsafe { MaybeUninit::zeroed().assume_init() };
-    /// WinConsole::input().read_utf16(&mut buffer);
+    /// use win32console::console::{WinConsole, ConsoleTextAttribute};
+    ///
+    /// let old_attributes = WinConsole::output().get_text_attribute().unwrap();
+    /// let new_attributes = ConsoleTextAttribute::BACKGROUND_BLUE;
+    ///
+    /// WinConsole::output().set_text_attribute(new_attributes);
+    /// WinConsole::output().write_utf8("Hello World!".as_bytes());
+    /// WinConsole::output().set_text_attribute(old_attributes);
     /// ```
-    pub fn read_utf16(&self, buffer: &mut [u16]) -> Result<usize> {
-        if buffer.len() == 0 {
-            return Ok(0);
-        }
-
-        // https://github.com/rust-lang/rust/blob/master/src/libstd/sys/windows/stdio.rs
-        // https://stackoverflow.com/questions/43836040/win-api-readconsole
-        const CTRL_Z: u16 = 0x1A;
-        const CTRL_Z_MASK: u32 = (1 << CTRL_Z) as u32;
-
-        let mut input_control = CONSOLE_READCONSOLE_CONTROL {
-            nLength: std::mem::size_of::<CONSOLE_READCONSOLE_CONTROL>() as u32,
-            nInitialChars: 0,
-            dwCtrlWakeupMask: CTRL_Z_MASK,
-            dwControlKeyState: 0,
-        };
-
+    pub fn set_text_attribute(&self, attribute: u16) -> Result<()> {
         let handle = self.get_handle();
-        let mut chars_read = 0;
-
-        if !WinConsole::is_console(&handle) {
-            let mut data = match String::from_utf16(buffer) {
-                Ok(string) => string,
-                Err(e) => return Err(Error::new(std::io::ErrorKind::InvalidInput, e)),
-            };
-
-            unsafe {
-                if ReadFile(
-                    **handle,
-                    data.as_mut_ptr() as *mut c_void,
-                    buffer.len() as u32,
-                    &mut chars_read,
-                    null_mut(),
-                ) == 0
-                {
-                    return Err(Error::last_os_error());
-                }
-            }
-
-            return Ok(chars_read as usize);
-        }
 
         unsafe {
-            if ReadConsoleW(
-                **handle,
-                buffer.as_mut_ptr() as *mut c_void,
-                buffer.len() as u32,
-                &mut chars_read,
-                &mut input_control,
-            ) == 0
-            {
-                Err(Error::last_os_error())
+            if SetConsoleTextAttribute(**handle, attribute) != 0 {
+                Ok(())
             } else {
-                if chars_read > 0 && buffer[chars_read as usize - 1] == CTRL_Z {
-                    chars_read -= 1;
-                }
-
-                Ok(chars_read as usize)
+                Err(Error::last_os_error())
             }
         }
     }
 
-    /// Fills the given `u8` buffer with characters from the standard input using the specified
-    /// console read control.
-    ///
-    /// - `control`: provides information used for a read operation as the number of chars
-    /// to skip or the end signal.
-    ///
-    /// Wraps a call to [ReadConsoleA](https://docs.microsoft.com/en-us/windows/console/readconsole).
-    ///
-    /// # Returns
-    /// The number of characters read.
+    /// Gets the text attributes of the characters in the console.
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
-    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    /// the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
-    /// use std::mem::MaybeUninit;
-    /// use win32console::console::WinConsole;
-    /// use win32console::structs::console_read_control::ConsoleReadControl;
+    /// use win32console::console::{WinConsole, ConsoleTextAttribute};
     ///
-    /// const CTRL_Z: u8 = 26;
-    /// const CTRL_Z_MASK: u32 = (1 << CTRL_Z) as u32;
+    /// let old_attributes = WinConsole::output().get_text_attribute().unwrap();
+    /// let new_attributes = ConsoleTextAttribute::BACKGROUND_BLUE;
     ///
-    /// let control = ConsoleReadControl::new_with_mask(CTRL_Z_MASK);
-    /// let mut buffer : [u8 ; 32] = unsafe { MaybeUninit::zeroed().assume_init() };
-    /// let mut len = WinConsole::input().read_utf8_with_control(&mut buffer, control).unwrap();
+    /// WinConsole::output().set_text_attribute(new_attributes);
+    /// WinConsole::output().write_utf8("Hello World!".as_bytes());
+    /// WinConsole::output().set_text_attribute(old_attributes);
+    /// ```
+    #[inline]
+    pub fn get_text_attribute(&self) -> Result<u16> {
+        Ok(self.get_screen_buffer_info()?.attributes)
+    }
+
+    /// Sets the text attribute of the characters in the console and returns the previous one,
+    /// using a single read of the screen buffer info and a single set of the attribute.
     ///
-    /// // If the last character is the control signal we ignore it.
-    /// if len > 0 && buffer[len - 1] == CTRL_Z{
-    ///     len -= 1;
-    /// }
+    /// - `new`: the attributes to use, those attributes can be access using `ConsoleTextAttribute` struct.
     ///
-    /// let string = String::from_utf8_lossy(&buffer[..len])
-    ///                     .trim() // String terminated in newline
-    ///                     .to_string();
+    /// # Remarks
+    /// This is not atomic, other processes attached to the same console can change the attribute
+    /// between the read and the set, this only avoids the redundant calls of this process.
     ///
-    /// // buffer is terminated in '\r\n', assertion will fail when write 32 characters
-    /// assert_eq!(len - 2, string.len());
-    /// WinConsole::output().write_utf8(string.as_bytes());
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
     /// ```
-    pub fn read_utf8_with_control(
-        &self,
-        buffer: &mut [u8],
-        control: ConsoleReadControl,
-    ) -> Result<usize> {
-        if buffer.len() == 0 {
-            return Ok(0);
-        }
-
-        let mut utf16_buffer = vec![u16::default(); buffer.len()];
-        let written = self.read_utf16_with_control(utf16_buffer.as_mut_slice(), control)?;
-        WinConsole::utf16_to_utf8(&utf16_buffer, buffer)?;
-        Ok(written)
+    /// use win32console::console::{WinConsole, ConsoleTextAttribute};
+    ///
+    /// let old_attributes = WinConsole::output().take_text_attribute(ConsoleTextAttribute::BACKGROUND_BLUE).unwrap();
+    /// WinConsole::output().write_utf8("Hello World!".as_bytes()).unwrap();
+    /// WinConsole::output().set_text_attribute(old_attributes).unwrap();
+    /// ```
+    pub fn take_text_attribute(&self, new: u16) -> Result<u16> {
+        let old = self.get_text_attribute()?;
+        self.set_text_attribute(new)?;
+        Ok(old)
     }
 
-    /// Fills the given `u16` buffer with characters from the standard input using the specified
-    /// console read control.
-    ///
-    /// - `control`: provides information used for a read operation as the number of chars
-    /// to skip or the end signal.
+    /// Enables or disables the underscore of the characters written after this call,
+    /// by setting the `ConsoleTextAttribute::COMMON_LVB_UNDERSCORE` bit of the current text attribute.
     ///
-    /// Wraps a call to [ReadConsoleW](https://docs.microsoft.com/en-us/windows/console/readconsole).
+    /// When enabling, the `ConsoleMode::ENABLE_LVB_GRID_WORLDWIDE` output mode is also enabled,
+    /// which is required to render the attribute with any code page.
     ///
-    /// # Returns
-    /// The number of characters read.
+    /// # Remarks
+    /// - The output mode is not restored when disabling, the `ConsoleMode::ENABLE_LVB_GRID_WORLDWIDE`
+    ///   mode stays enabled until is changed using [`set_mode`].
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
-    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
-    /// use std::mem::MaybeUninit;
     /// use win32console::console::WinConsole;
-    /// use win32console::structs::console_read_control::ConsoleReadControl;
     ///
-    /// const CTRL_Z: u16 = 26;
-    /// const CTRL_Z_MASK: u32 = (1 << CTRL_Z) as u32;
+    /// let old_mode = WinConsole::output().get_mode().unwrap();
+    /// WinConsole::output().set_underscore(true).unwrap();
+    /// WinConsole::output().write_utf8(b"Underlined").unwrap();
+    /// WinConsole::output().set_underscore(false).unwrap();
     ///
-    /// let control = ConsoleReadControl::new_with_mask(CTRL_Z_MASK);
-    /// let mut buffer : [u16 ; 32] = unsafe { MaybeUninit::zeroed().assume_init() };
-    /// let mut len = WinConsole::input().read_utf16_with_control(&mut buffer, control).unwrap();
+    /// // Restores the output mode
+    /// WinConsole::output().set_mode(old_mode).unwrap();
+    /// ```
     ///
-    /// // If the last character is the control signal we ignore it.
-    /// if len > 0 && buffer[len - 1] == CTRL_Z{
-    ///     len -= 1;
-    /// }
+    /// [`set_mode`]: #method.set_mode
+    pub fn set_underscore(&self, enable: bool) -> Result<()> {
+        self.set_lvb_attribute(ConsoleTextAttribute::COMMON_LVB_UNDERSCORE, enable)
+    }
+
+    /// Enables or disables the reverse video of the characters written after this call,
+    /// by setting the `ConsoleTextAttribute::COMMON_LVB_REVERSE_VIDEO` bit of the current text attribute.
     ///
-    /// let string = String::from_utf16_lossy(&buffer[..len])
-    ///                     .trim() // String terminated in newline
-    ///                     .to_string();
+    /// When enabling, the `ConsoleMode::ENABLE_LVB_GRID_WORLDWIDE` output mode is also enabled,
+    /// which is required to render the attribute with any code page.
     ///
-    /// // buffer is terminated in '\r\n', assertion will fail when write 32 characters
-    /// assert_eq!(len - 2, string.len());
-    /// WinConsole::output().write_utf8(string.as_bytes());
+    /// # Remarks
+    /// - The output mode is not restored when disabling, the `ConsoleMode::ENABLE_LVB_GRID_WORLDWIDE`
+    ///   mode stays enabled until is changed using [`set_mode`].
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
     /// ```
-    pub fn read_utf16_with_control(
-        &self,
-        buffer: &mut [u16],
-        control: ConsoleReadControl,
-    ) -> Result<usize> {
-        if buffer.len() == 0 {
-            return Ok(0);
-        }
-
-        let mut input_control = control.into();
-        let handle = self.get_handle();
-        let mut chars_read = 0;
+    /// use win32console::console::WinConsole;
+    ///
+    /// let old_mode = WinConsole::output().get_mode().unwrap();
+    /// WinConsole::output().set_reverse_video(true).unwrap();
+    /// WinConsole::output().write_utf8(b"Reversed").unwrap();
+    /// WinConsole::output().set_reverse_video(false).unwrap();
+    ///
+    /// // Restores the output mode
+    /// WinConsole::output().set_mode(old_mode).unwrap();
+    /// ```
+    ///
+    /// [`set_mode`]: #method.set_mode
+    pub fn set_reverse_video(&self, enable: bool) -> Result<()> {
+        self.set_lvb_attribute(ConsoleTextAttribute::COMMON_LVB_REVERSE_VIDEO, enable)
+    }
 
-        if !WinConsole::is_console(&handle) {
-            let mut data = match String::from_utf16(buffer) {
-                Ok(string) => string,
-                Err(e) => return Err(Error::new(std::io::ErrorKind::InvalidInput, e)),
-            };
+    /// Sets or clears the given `COMMON_LVB_*` bit of the current text attribute.
+    fn set_lvb_attribute(&self, flag: u16, enable: bool) -> Result<()> {
+        let attribute = self.get_text_attribute()?;
 
-            unsafe {
-                if ReadFile(
-                    **handle,
-                    data.as_mut_ptr() as *mut c_void,
-                    buffer.len() as u32,
-                    &mut chars_read,
-                    null_mut(),
-                ) == 0
-                {
-                    return Err(Error::last_os_error());
-                }
+        if enable {
+            let mode = self.get_mode()?;
+            if mode & ConsoleMode::ENABLE_LVB_GRID_WORLDWIDE == 0 {
+                // Legacy consoles don't support the mode, but still render the attribute with some code pages
+                let _ = self.set_mode(mode | ConsoleMode::ENABLE_LVB_GRID_WORLDWIDE);
             }
 
-            return Ok(chars_read as usize);
-        }
-
-        unsafe {
-            if ReadConsoleW(
-                **handle,
-                buffer.as_mut_ptr() as *mut c_void,
-                buffer.len() as u32,
-                &mut chars_read,
-                &mut input_control,
-            ) == 0
-            {
-                Err(Error::last_os_error())
-            } else {
-                Ok(chars_read as usize)
-            }
+            self.set_text_attribute(attribute | flag)
+        } else {
+            self.set_text_attribute(attribute & !flag)
         }
     }
 
-    /// Flushes the console input buffer. All input records currently in the input buffer are discarded.
+    /// Gets the largest size the console window can get.
     ///
-    /// Wraps a call to [FlushConsoleInputBuffer](https://docs.microsoft.com/en-us/windows/console/flushconsoleinputbuffer).
+    /// Wraps a call to [GetLargestConsoleWindowSize](https://docs.microsoft.com/en-us/windows/console/getlargestconsolewindowsize).
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
-    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    /// the function should be called using `WinConsole::output()` or a valid output handle.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// WinConsole::input().flush_input();
+    /// let max_size = WinConsole::output().get_largest_window_size().unwrap();
     /// ```
-    pub fn flush_input(&self) -> Result<()>{
+    pub fn get_largest_window_size(&self) -> Result<Coord> {
         let handle = self.get_handle();
 
         unsafe {
-            if FlushConsoleInputBuffer(**handle) == 0{
+            let coord: Coord = GetLargestConsoleWindowSize(**handle).into();
+
+            if coord == Coord::ZERO {
                 Err(Error::last_os_error())
-            }
-            else{
-                Ok(())
+            } else {
+                Ok(coord)
             }
         }
     }
 
-    /// Writes the specified `u8` buffer of chars in the current cursor position of the console.
-    ///
-    /// Wraps a call to [WriteConsoleA](https://docs.microsoft.com/en-us/windows/console/writeconsole).
+    /// Gets the number of unread input events.
     ///
-    /// # Returns
-    /// The number of characters written.
+    /// Wraps a call to [GetNumberOfConsoleInputEvents](https://docs.microsoft.com/en-us/windows/console/getnumberofconsoleinputevents).
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
-    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    /// the function should be called using `WinConsole::input()` or a valid input handle.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// WinConsole::output().write_utf8("Hello World!".as_bytes());
+    /// let unread_events = WinConsole::input().get_number_of_input_events().unwrap();
     /// ```
-    pub fn write_utf8(&self, data: &[u8]) -> Result<usize> {
-        if data.len() == 0 {
-            return Ok(0);
-        }
-
+    pub fn get_number_of_input_events(&self) -> Result<usize> {
         let handle = self.get_handle();
-        let mut chars_written = 0;
-
-        // If is being redirected write to the handle
-        if !WinConsole::is_console(&handle) {
-            let buf = match String::from_utf8(data.to_vec()) {
-                Ok(string) => string,
-                Err(e) => return Err(Error::new(std::io::ErrorKind::InvalidInput, e)),
-            };
-
-            unsafe {
-                if WriteFile(
-                    **handle,
-                    buf.as_ptr() as *const c_void,
-                    data.len() as u32,
-                    &mut chars_written,
-                    null_mut(),
-                ) == 0
-                {
-                    return Err(Error::last_os_error());
-                }
-            }
-            return Ok(data.len());
-        }
 
         unsafe {
-            if WriteConsoleA(
-                **handle,
-                data.as_ptr() as *const c_void,
-                data.len() as u32,
-                &mut chars_written,
-                null_mut(),
-            ) == 0
-            {
+            let mut num_events = 0;
+            if GetNumberOfConsoleInputEvents(**handle, &mut num_events) == 0 {
                 Err(Error::last_os_error())
             } else {
-                assert_eq!(chars_written, data.len() as u32);
-                Ok(chars_written as usize)
+                Ok(num_events as usize)
             }
         }
     }
 
-    /// Writes the specified buffer of chars in the current cursor position of the console.
+    /// Checks if there are unread input events in the console input buffer.
     ///
-    /// Wraps a call to [WriteConsoleW](https://docs.microsoft.com/en-us/windows/console/writeconsole).
-    ///
-    /// # Returns
-    /// The number of characters written.
+    /// Wraps a call to [GetNumberOfConsoleInputEvents](https://docs.microsoft.com/en-us/windows/console/getnumberofconsoleinputevents).
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
-    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    ///   the function should be called using `WinConsole::input()` or a valid input handle.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// let x = "Hello World!".encode_utf16().collect::<Vec<u16>>();
-    /// WinConsole::output().write_utf16(x.as_slice());
+    ///
+    /// let input = WinConsole::input();
+    /// if input.has_pending_input().unwrap(){
+    ///     let record = input.read_single_input().unwrap();
+    /// }
     /// ```
-    pub fn write_utf16(&self, data: &[u16]) -> Result<usize> {
-        if data.len() == 0 {
-            return Ok(0);
-        }
-
-        let handle = self.get_handle();
-        let mut chars_written = 0;
-
-        // If is being redirected write to the handle
-        if !WinConsole::is_console(&handle) {
-            let buf = match String::from_utf16(data) {
-                Ok(string) => string,
-                Err(e) => return Err(Error::new(std::io::ErrorKind::InvalidInput, e)),
-            };
-
-            unsafe {
-                if WriteFile(
-                    **handle,
-                    buf.as_ptr() as *const c_void,
-                    data.len() as u32,
-                    &mut chars_written,
-                    null_mut(),
-                ) == 0
-                {
-                    return Err(Error::last_os_error());
-                }
-            }
-            return Ok(data.len());
-        }
-
-        unsafe {
-            if WriteConsoleW(
-                **handle,
-                data.as_ptr() as *const c_void,
-                data.len() as u32,
-                &mut chars_written,
-                null_mut(),
-            ) == 0
-            {
-                Err(Error::last_os_error())
-            } else {
-                assert_eq!(chars_written, data.len() as u32);
-                Ok(chars_written as usize)
-            }
-        }
+    pub fn has_pending_input(&self) -> Result<bool> {
+        Ok(self.get_number_of_input_events()? > 0)
     }
 
-    /// Writes the given buffer of `CharInfo` into the screen buffer.
-    ///
-    /// Wraps a call to [WriteConsoleOutputW](https://docs.microsoft.com/en-us/windows/console/writeconsoleoutput).
+    /// Gets the approximate number of input events the console input buffer can still hold,
+    /// before the new input events are dropped.
     ///
-    /// See also: [`https://www.randygaul.net/2011/11/16/windows-console-game-writing-to-the-console/`]
-    ///
-    /// - `buffer_size`: the size of the `buffer` in rows and columns.
-    /// - `buffer_start`: the origin in the `buffer` where start to take the characters to write, typically (0,0).
-    /// - `write_area`: Represents the screen buffer area to write to.
+    /// Wraps a call to [GetNumberOfConsoleInputEvents](https://docs.microsoft.com/en-us/windows/console/getnumberofconsoleinputevents).
     ///
     /// # Remarks
-    /// - This functions don't affect the cursor position.
-    /// - If the `write_area` is outside the screen buffer no data is written.
+    /// There is no API to get the capacity of the input buffer, so this is an approximation
+    /// against its default capacity of 256 input events. Some console hosts grow the input buffer
+    /// as needed, so the actual number of events it can hold may be larger.
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
-    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    ///   the function should be called using `WinConsole::input()` or a valid input handle.
     ///
     /// # Example
     /// ```
-    /// use win32console::structs::coord::Coord;
     /// use win32console::console::WinConsole;
-    /// use win32console::structs::char_info::CharInfo;
-    /// use win32console::structs::small_rect::SmallRect;
-    /// const WIDTH : usize = 40;
-    /// const HEIGHT : usize = 30;
     ///
-    /// let mut buffer = Vec::with_capacity(WIDTH * HEIGHT);
-    /// let buffer_size = Coord::new(WIDTH as i16, HEIGHT as i16);
-    /// let window = SmallRect::new(0, 0, (WIDTH - 1) as i16, (HEIGHT - 1) as i16);
+    /// WinConsole::input().flush_input().unwrap();
+    /// assert_eq!(256, WinConsole::input().input_buffer_remaining().unwrap());
+    /// ```
+    pub fn input_buffer_remaining(&self) -> Result<usize> {
+        let num_events = self.get_number_of_input_events()?;
+        Ok(WinConsole::INPUT_BUFFER_CAPACITY.saturating_sub(num_events))
+    }
+
+    /// Checks if the console input buffer is near to be full, that is when it can hold 32 or less
+    /// input events, so the caller should read the pending input events to avoid lose new events.
     ///
-    /// WinConsole::output().set_window_info(true, &window).unwrap();
-    /// WinConsole::output().set_screen_buffer_size(buffer_size.clone()).unwrap();
+    /// See [`input_buffer_remaining`] for how the remaining space is approximated.
     ///
-    /// for i in 0..buffer.capacity(){
-    ///    let char_info = CharInfo::new(' ', (16 << i % 3) as u16);
-    ///     buffer.push(char_info);
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    ///   the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// let input = WinConsole::input();
+    /// if input.is_input_buffer_near_full().unwrap(){
+    ///     let records = input.read_available_input().unwrap();
     /// }
+    /// ```
     ///
-    /// WinConsole::output().write_output(buffer.as_ref(), buffer_size, Coord::ZERO, window).unwrap();
+    /// [`input_buffer_remaining`]: #method.input_buffer_remaining
+    pub fn is_input_buffer_near_full(&self) -> Result<bool> {
+        Ok(self.input_buffer_remaining()? <= WinConsole::INPUT_BUFFER_NEAR_FULL)
+    }
+
+    /// Gets the number of mouse buttons used for the mouse available for this console.
+    ///
+    /// Wraps a call to [GetNumberOfConsoleMouseButtons](https://docs.microsoft.com/en-us/windows/console/getnumberofconsolemousebuttons).
+    ///
+    /// # Errors
+    /// - No documented errors.
+    ///
+    /// # Example
     /// ```
-    pub fn write_output(
-        &self,
-        buffer: &[CharInfo],
-        buffer_size: Coord,
-        buffer_start: Coord,
-        write_area: SmallRect,
-    ) -> Result<()> {
-        if buffer.len() == 0 {
-            return Ok(());
+    /// use win32console::console::WinConsole;
+    /// let x = WinConsole::input().get_number_of_mouse_buttons().unwrap();
+    /// let y = WinConsole::output().get_number_of_mouse_buttons().unwrap();
+    /// assert_eq!(x, y);
+    /// ```
+    pub fn get_number_of_mouse_buttons(&self) -> Result<u32> {
+        let mut num_buttons = 0;
+
+        unsafe {
+            if GetNumberOfConsoleMouseButtons(&mut num_buttons) == 0 {
+                Err(Error::last_os_error())
+            } else {
+                Ok(num_buttons)
+            }
         }
+    }
 
+    /// Moves a block of data in a screen buffer.
+    /// The effects of the move can be limited by specifying a clipping rectangle,
+    /// so the contents of the console screen buffer outside the clipping rectangle are unchanged.
+    ///
+    /// Wraps a call to [ScrollConsoleScreenBufferW](https://docs.microsoft.com/en-us/windows/console/scrollconsolescreenbuffer).
+    ///
+     /// # Errors
+    /// - No documented errors.
+    pub fn scroll_screen_buffer(&self,
+                                scroll_rect: SmallRect,
+                                clip_rect: Option<SmallRect>,
+                                destination: Coord,
+                                fill: CharInfo
+    ) -> Result<()>{
         let handle = self.get_handle();
-        let write_area_raw: PSMALL_RECT = &mut write_area.into();
-
-        let buf = buffer
-            .iter()
-            .map(|c| (*c).into())
-            .collect::<Vec<CHAR_INFO>>();
+        let chi = &mut fill.into();
+        let srect = &mut scroll_rect.into();
+        let crect = match clip_rect{
+            Some(r) => &mut r.into(),
+            None => null_mut()
+        };
 
-        unsafe {
-            if WriteConsoleOutputW(
+        unsafe{
+            if ScrollConsoleScreenBufferW(
                 **handle,
-                buf.as_ptr() as PCHAR_INFO,
-                buffer_size.into(),
-                buffer_start.into(),
-                write_area_raw,
-            ) == 0
-            {
+                srect,
+                crect,
+                destination.into(),
+                chi) == 0{
                 Err(Error::last_os_error())
-            } else {
+            }
+            else{
                 Ok(())
             }
         }
     }
 
-    /// Writes data directly to the console input buffer.
-    ///
-    /// Wraps a call to [WriteConsoleInputA](https://docs.microsoft.com/en-us/windows/console/writeconsoleinput).
+    /// Reads a single event from the console.
     ///
     /// # Errors
     /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
@@ -2759,269 +4091,2538 @@ impl WinConsole {
     ///
     /// # Example
     /// ```
-    /// use win32console::structs::input_record::InputRecord;
-    /// use win32console::structs::input_event::{KeyEventRecord, ControlKeyState};
     /// use win32console::structs::input_record::InputRecord::KeyEvent;
     /// use win32console::console::WinConsole;
-    /// use winapi::_core::mem::MaybeUninit;
-    ///
-    /// let mut key_event : KeyEventRecord = unsafe { std::mem::zeroed() };
-    /// key_event.repeat_count = 1;
-    /// key_event.control_key_state = ControlKeyState::new(0);
-    /// key_event.u_char = 'a';
-    /// key_event.key_down = true;
-    /// key_event.virtual_scan_code = 0;
-    /// key_event.virtual_key_code = 0x41;
     ///
-    /// // Discard all the records in the buffer
-    /// WinConsole::input().flush_input();
-    ///
-    /// let record : [InputRecord; 1] = [KeyEvent(key_event)];
-    /// WinConsole::input().write_input(&record).expect("Cannot write the event");
-    ///
-    /// let mut buf : [InputRecord; 1] = unsafe { MaybeUninit::zeroed().assume_init() };
-    /// WinConsole::input().peek_input(&mut buf).expect("Cannot peek the events");
-    ///
-    /// assert_eq!(record, buf);
+    /// loop{
+    ///        // A simple alphanumeric reader from the std input
+    ///        if let KeyEvent(event) = WinConsole::input().read_single_input().unwrap(){
+    ///             // Only enter when the key is pressed down
+    ///            if event.key_down{
+    ///                // Only alphanumeric are allowed so any other is ignore
+    ///                if !(event.u_char.is_ascii_alphanumeric()) {
+    ///                    match event.virtual_key_code{
+    ///                        0x1B => { break; }         // Exit when escape is press
+    ///                        _ => {}
+    ///                    }
+    ///                }
+    ///                 else {
+    ///                    // Write the character
+    ///                    WinConsole::output().write_char(event.u_char);
+    ///                 }
+    ///            }
+    ///        }
+    ///    }
     /// ```
-    pub fn write_input(&self, buffer: &[InputRecord]) -> Result<usize>{
-        if buffer.len() == 0{
-            return Ok(0);
-        }
-
-        let mut buf = buffer.iter()
-            .map(|c| (*c).into())
-            .collect::<Vec<INPUT_RECORD>>();
-
-        let handle = self.get_handle();
-        let mut events_written = 0;
-
-        unsafe{
-            if WriteConsoleInputA(**handle, buf.as_mut_ptr(), buf.len() as u32, &mut events_written) == 0{
-                Err(Error::last_os_error())
-            }
-            else{
-                Ok(events_written as usize)
-            }
+    pub fn read_single_input(&self) -> Result<InputRecord> {
+        unsafe {
+            let mut record: InputRecord = std::mem::zeroed();
+            let mut buf = slice::from_mut(&mut record);
+            self.read_input(&mut buf)?;
+            Ok(record)
         }
     }
 
-    /// Copies a number of character attributes to consecutive cells of a console screen buffer, beginning at a specified location.
+    /// Waits until an input event is available or the timeout elapses, and reads a single input event from the console.
     ///
-    /// Wraps a call to [WriteConsoleOutputAttribute](https://docs.microsoft.com/en-us/windows/console/writeconsoleoutputattribute).
+    /// Wraps a call to [WaitForSingleObject](https://docs.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-waitforsingleobject)
+    /// and [ReadConsoleInputW](https://docs.microsoft.com/en-us/windows/console/readconsoleinput).
+    ///
+    /// # Returns
+    /// The input event read or `None` if the timeout elapsed before any input event is available.
     ///
     /// # Errors
     /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
-    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    ///   the function should be called using `WinConsole::input()` or a valid input handle.
     ///
-    /// # Examples
+    /// # Example
     /// ```
-    /// use win32console::console::{WinConsole, ConsoleTextAttribute};
-    /// use win32console::structs::coord::Coord;
+    /// use std::time::Duration;
+    /// use win32console::console::WinConsole;
     ///
-    /// WinConsole::output().clear();
-    /// WinConsole::output().write_utf8(b"RGB");
+    /// // Discard all the records in the buffer
+    /// WinConsole::input().flush_input().unwrap();
     ///
-    /// let attributes : [u16; 3] = [ConsoleTextAttribute::FOREGROUND_RED, ConsoleTextAttribute::FOREGROUND_GREEN, ConsoleTextAttribute::FOREGROUND_BLUE];
-    /// WinConsole::output().write_output_attribute(&attributes, Coord::ZERO);
+    /// let record = WinConsole::input().read_single_input_timeout(Duration::from_millis(10)).unwrap();
+    /// assert!(record.is_none());
     /// ```
-    pub fn write_output_attribute(&self, attributes: &[u16], write_coord: Coord) -> Result<usize>{
-        if attributes.len() == 0{
-            return Ok(0);
+    pub fn read_single_input_timeout(&self, timeout: Duration) -> Result<Option<InputRecord>> {
+        if self.wait_for_input(timeout)? {
+            self.read_single_input().map(Some)
+        } else {
+            Ok(None)
         }
+    }
 
-        let handle = self.get_handle();
-
-        unsafe{
-            let mut written_attributes = 0;
-            if WriteConsoleOutputAttribute(**handle, attributes.as_ptr(), attributes.len() as u32, write_coord.into(), &mut written_attributes) == 0{
-                Err(Error::last_os_error())
-            }
-            else{
-                Ok(written_attributes as usize)
+    /// Reads input events from the console until one matches the given predicate,
+    /// the events that don't match are discarded.
+    ///
+    /// Wraps a call to [ReadConsoleInputW](https://docs.microsoft.com/en-us/windows/console/readconsoleinput).
+    ///
+    /// # Returns
+    /// The first input event that matches the predicate.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    ///   the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::input_record::InputRecord;
+    ///
+    /// // Waits until the Enter key is pressed
+    /// WinConsole::input().read_until(|record| match record {
+    ///     InputRecord::KeyEvent(event) => event.key_down && event.virtual_key_code == 0x0D,
+    ///     _ => false
+    /// }).unwrap();
+    /// ```
+    pub fn read_until<F: Fn(&InputRecord) -> bool>(&self, pred: F) -> Result<InputRecord> {
+        loop {
+            let record = self.read_single_input()?;
+            if pred(&record) {
+                return Ok(record);
             }
         }
     }
 
-    /// Copies a number of characters to consecutive cells of a console screen buffer, beginning at a specified location.
+    /// Reads up to `max` input events from the console, returning early when no new
+    /// input event arrives within the `idle` duration.
     ///
-    /// Wraps a call to [WriteConsoleOutputCharacterW](https://docs.microsoft.com/en-us/windows/console/writeconsoleoutputcharacter).
+    /// - `max`: the maximum number of input events to read.
+    /// - `idle`: the time to wait for the next input event before return.
+    ///
+    /// Wraps a call to [WaitForSingleObject](https://docs.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-waitforsingleobject)
+    /// and [ReadConsoleInputW](https://docs.microsoft.com/en-us/windows/console/readconsoleinput).
+    ///
+    /// # Remarks
+    /// - The `idle` time is measured from the last event read, not from the start of the call,
+    ///   so a continuous stream of events is read until `max` events are read.
+    /// - If no event arrives within `idle` since the call an empty `Vec` is returned.
+    /// - All the events available in the input buffer are read at once, up to `max`.
     ///
     /// # Errors
     /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
-    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    ///   the function should be called using `WinConsole::input()` or a valid input handle.
     ///
     /// # Example
     /// ```
+    /// use std::time::Duration;
     /// use win32console::console::WinConsole;
-    /// use win32console::structs::coord::Coord;
     ///
-    /// WinConsole::output().clear();
-    /// WinConsole::output().write_utf8("*".repeat(15).as_bytes());
-    /// WinConsole::output().write_output_character(b"Hello", Coord::new(5, 0));
+    /// // Reads the input of the current frame
+    /// let records = WinConsole::input().read_input_up_to(64, Duration::from_millis(16)).unwrap();
+    /// for record in records{
+    ///     println!("{}", record);
+    /// }
     /// ```
-    pub fn write_output_character(&self, buffer: &[u8], write_coord: Coord) -> Result<usize>{
-        if buffer.len() == 0{
-            return Ok(0);
-        }
-
-        let handle = self.get_handle();
-        let mut chars_written = 0;
-        let utf16_buffer = match str::from_utf8(buffer){
-            Ok(string) => {
-                string.encode_utf16().collect::<Vec<u16>>()
-            },
-            Err(e) => {
-                return Err(Error::new(ErrorKind::InvalidData, e));
-            },
-        };
+    pub fn read_input_up_to(&self, max: usize, idle: Duration) -> Result<Vec<InputRecord>> {
+        let mut records = Vec::new();
 
-        unsafe{
-            if WriteConsoleOutputCharacterW(**handle, utf16_buffer.as_ptr(), utf16_buffer.len() as u32, write_coord.into(), &mut chars_written) == 0{
-                Err(Error::last_os_error())
-            }
-            else{
-                Ok(chars_written as usize)
-            }
+        while records.len() < max && self.wait_for_input(idle)? {
+            let available = self.get_number_of_input_events()?;
+            let count = available.max(1).min(max - records.len());
+            records.extend(self.read_input_n(count)?);
         }
-    }
 
-    /// Checks if the handle is a handle to a console
-    #[inline]
-    fn is_console(handle: &Handle) -> bool {
-        let mut mode = 0;
-        unsafe { GetConsoleMode(**handle, &mut mode) != 0 }
+        Ok(records)
     }
 
-    /// Converts the content of the given utf16 buffer to utf8 and writes it to the
-    /// destination buffer.
-    fn utf16_to_utf8(source: &[u16], destination: &mut [u8]) -> Result<usize> {
-        // The actual number of utf8 characters written to the destination buffer
-        let mut written = 0;
+    /// Waits until an input event is available or the timeout elapses,
+    /// returns `false` if the timeout elapsed.
+    fn wait_for_input(&self, timeout: Duration) -> Result<bool> {
+        let handle = self.get_handle();
+        // `INFINITE` is not a valid timeout, so the largest is 1 millisecond less
+        let millis = timeout.as_millis().min((INFINITE - 1) as u128) as u32;
 
-        let utf16_iterator = source.iter().cloned();
-        for chr in std::char::decode_utf16(utf16_iterator) {
-            match chr {
-                Ok(value) => {
-                    value.encode_utf8(&mut destination[written..]);
-                    written += value.len_utf8();
-                }
-                Err(e) => {
-                    return Err(Error::new(ErrorKind::InvalidData, e));
-                }
-            }
+        match unsafe { WaitForSingleObject(**handle, millis) } {
+            WAIT_OBJECT_0 => Ok(true),
+            WAIT_TIMEOUT => Ok(false),
+            _ => Err(Error::last_os_error()),
         }
-
-        Ok(written)
     }
-}
-
-// ConsoleColor methods
-impl WinConsole {
-    const FG_COLOR_MARK: u16 = 0xF;
-    const BG_COLOR_MASK: u16 = 0xF0;
 
-    /// Gets the foreground color of the console.
+    /// Reads input events from the console until a focus event is found and returns
+    /// whether the console window gained the focus, any other input event read is discarded.
+    ///
+    /// The focus events are only reported when the `ENABLE_WINDOW_INPUT` mode is set,
+    /// so this method enables it while waiting and restores the previous console mode before return.
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
-    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    ///   the function should be called using `WinConsole::input()` or a valid input handle.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// use win32console::structs::console_color::ConsoleColor;
-    /// let fg = WinConsole::output().get_foreground_color().unwrap();
-    /// let bg = WinConsole::output().get_background_color().unwrap();
+    /// use win32console::structs::focus_event::FocusEventRecord;
+    /// use win32console::structs::input_record::InputRecord::FocusEvent;
     ///
-    /// WinConsole::output().set_foreground_color(ConsoleColor::Red);
-    /// WinConsole::output().set_background_color(ConsoleColor::Yellow);
-    /// WinConsole::output().write_utf8("Hello World!".as_bytes());
+    /// // Discard all the records in the buffer
+    /// WinConsole::input().flush_input().unwrap();
     ///
-    /// // Restore colors
-    /// WinConsole::output().set_foreground_color(fg);
-    /// WinConsole::output().set_background_color(bg);
+    /// WinConsole::input().write_input(&[FocusEvent(FocusEventRecord{ set_focus: false })]).unwrap();
+    /// assert!(!WinConsole::input().wait_for_focus_change().unwrap());
     /// ```
-    #[inline]
-    pub fn get_foreground_color(&self) -> Result<ConsoleColor> {
-        let attributes = self.get_text_attribute()?;
-        Ok(ConsoleColor::try_from(attributes & WinConsole::FG_COLOR_MARK).unwrap())
+    pub fn wait_for_focus_change(&self) -> Result<bool> {
+        let old_mode = self.get_mode()?;
+        if old_mode & ConsoleMode::ENABLE_WINDOW_INPUT == 0 {
+            self.set_mode(old_mode | ConsoleMode::ENABLE_WINDOW_INPUT)?;
+        }
+
+        let result = loop {
+            match self.read_single_input() {
+                Ok(InputRecord::FocusEvent(event)) => break Ok(event.set_focus),
+                Ok(_) => {}
+                Err(e) => break Err(e),
+            }
+        };
+
+        if old_mode & ConsoleMode::ENABLE_WINDOW_INPUT == 0 {
+            self.set_mode(old_mode)?;
+        }
+
+        result
     }
 
-    /// Gets the background color of the console.
+    /// Gets an iterator that reads input events from the console and yields the new size of the
+    /// console screen buffer each time a `WindowBufferSizeEvent` is read, any other input event is discarded.
+    ///
+    /// The resize events are only reported when the `ENABLE_WINDOW_INPUT` mode is set,
+    /// so this method enables it and the previous console mode is restored when the iterator is dropped.
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
-    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    ///   the function should be called using `WinConsole::input()` or a valid input handle.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// use win32console::structs::console_color::ConsoleColor;
-    /// let fg = WinConsole::output().get_foreground_color().unwrap();
-    /// let bg = WinConsole::output().get_background_color().unwrap();
+    /// use win32console::structs::coord::Coord;
+    /// use win32console::structs::window_buffer_size_event::WindowBufferSizeRecord;
+    /// use win32console::structs::input_record::InputRecord::WindowBufferSizeEvent;
     ///
-    /// WinConsole::output().set_foreground_color(ConsoleColor::Black);
-    /// WinConsole::output().set_background_color(ConsoleColor::White);
-    /// WinConsole::output().write_utf8("Hello World!".as_bytes());
+    /// // Discard all the records in the buffer
+    /// WinConsole::input().flush_input().unwrap();
     ///
-    /// // Restore colors
-    /// WinConsole::output().set_foreground_color(fg);
-    /// WinConsole::output().set_background_color(bg);
+    /// let console = WinConsole::input();
+    /// let mut events = console.resize_events().unwrap();
+    ///
+    /// console.write_input(&[WindowBufferSizeEvent(WindowBufferSizeRecord{ size: Coord::new(80, 25) })]).unwrap();
+    /// assert_eq!(Coord::new(80, 25), events.next().unwrap().unwrap());
     /// ```
-    #[inline]
-    pub fn get_background_color(&self) -> Result<ConsoleColor> {
-        let attributes = self.get_text_attribute()?;
-        Ok(ConsoleColor::try_from(attributes & WinConsole::BG_COLOR_MASK).unwrap())
+    pub fn resize_events(&self) -> Result<ResizeEvents<'_>> {
+        let old_mode = self.get_mode()?;
+        if old_mode & ConsoleMode::ENABLE_WINDOW_INPUT == 0 {
+            self.set_mode(old_mode | ConsoleMode::ENABLE_WINDOW_INPUT)?;
+        }
+
+        Ok(ResizeEvents{ console: self, old_mode })
     }
 
-    /// Sets the foreground color of the console.
+    /// Gets an iterator that reads key events from the console and yields the printable characters
+    /// typed, any other input event, key up event or control character is discarded.
     ///
-    /// # Errors
-    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
-    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    /// The characters are not displayed in the console, echo them is left to the caller.
+    ///
+    /// # Remarks
+    /// - Each call to `next` blocks until a printable character is typed.
+    /// - A key event with a `repeat_count` greater than 1 yields its character that number of times.
+    /// - The iterator ends if an error occurs while reading the input events.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// use win32console::structs::console_color::ConsoleColor;
-    /// let fg = WinConsole::output().get_foreground_color().unwrap();
-    /// let bg = WinConsole::output().get_background_color().unwrap();
     ///
-    /// WinConsole::output().set_foreground_color(ConsoleColor::Yellow);
-    /// WinConsole::output().set_background_color(ConsoleColor::DarkMagenta);
-    /// WinConsole::output().write_utf8("Hello World!".as_bytes());
-    ///
-    /// // Restore colors
-    /// WinConsole::output().set_foreground_color(fg);
-    /// WinConsole::output().set_background_color(bg);
+    /// let input = WinConsole::input();
+    /// let name : String = input.printable_chars().take(3).collect();
+    /// WinConsole::output().write_utf8(name.as_bytes()).unwrap();
     /// ```
-    pub fn set_foreground_color(&self, color: ConsoleColor) -> Result<()> {
-        let old_attributes = self.get_text_attribute()?;
-        let new_attributes = (old_attributes
-            & !(old_attributes & WinConsole::FG_COLOR_MARK)) | color.as_foreground_color();
-        self.set_text_attribute(new_attributes)
+    pub fn printable_chars(&self) -> PrintableChars<'_> {
+        PrintableChars{ console: self, pending: None }
     }
 
-    /// Sets the background color of the console.
+    /// Reads input events from the console.
+    ///
+    /// - `buffer_size`: the size of the buffer that will store the events.
+    ///
+    /// # Returns
+    /// The events read, which may be fewer than `buffer_size`.
     ///
     /// # Errors
-    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
-    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    /// the function should be called using `WinConsole::input()` or a valid input handle.
     ///
     /// # Example
     /// ```
     /// use win32console::console::WinConsole;
-    /// use win32console::structs::console_color::ConsoleColor;
-    /// let fg = WinConsole::output().get_foreground_color().unwrap();
-    /// let bg = WinConsole::output().get_background_color().unwrap();
+    /// use win32console::structs::input_record::InputRecord::KeyEvent;
+    /// let input_records = WinConsole::input().read_input_n(10).unwrap();
     ///
-    /// WinConsole::output().set_foreground_color(ConsoleColor::DarkBlue);
-    /// WinConsole::output().set_background_color(ConsoleColor::Green);
-    /// WinConsole::output().write_utf8("Hello World!".as_bytes());
+    /// let mut buf = String::new();
+    /// for record in input_records{
+    ///     if let KeyEvent(key) = record{
+    ///         if key.key_down && key.u_char.is_ascii_alphanumeric(){
+    ///             buf.push(key.u_char);
+    ///         }
+    ///     }
+    /// }
     ///
-    /// // Restore colors
-    /// WinConsole::output().set_foreground_color(fg);
+    /// WinConsole::output().write_utf8(buf.as_bytes());
+    /// ```
+    pub fn read_input_n(&self, buffer_size: usize) -> Result<Vec<InputRecord>> {
+        if buffer_size == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut buffer = vec![unsafe { std::mem::zeroed::<InputRecord>() }; buffer_size];
+
+        let num_events = self.read_input(buffer.as_mut_slice())?;
+        buffer.truncate(num_events);
+        Ok(buffer)
+    }
+
+    /// Reads all the input events currently available in the console input buffer at once.
+    ///
+    /// The number of events is queried with [`get_number_of_input_events`] and exactly that many
+    /// records are read, so this function don't block when there are events in the buffer.
+    ///
+    /// # Remarks
+    /// - If the input buffer is empty this function blocks until at least one event is available,
+    ///   like [`read_input`].
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    ///   the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::input_record::InputRecord;
+    ///
+    /// let records : Vec<InputRecord> = WinConsole::input().read_available_input().unwrap();
+    /// for record in records{
+    ///     println!("{}", record);
+    /// }
+    /// ```
+    ///
+    /// [`get_number_of_input_events`]: #method.get_number_of_input_events
+    /// [`read_input`]: #method.read_input
+    pub fn read_available_input(&self) -> Result<Vec<InputRecord>> {
+        let num_events = self.get_number_of_input_events()?.max(1);
+        self.read_input_n(num_events)
+    }
+
+    /// Fills the specified buffer with [`InputRecord`] from the console.
+    ///
+    /// Wraps a call to [ReadConsoleInputW](https://docs.microsoft.com/en-us/windows/console/readconsoleinput).
+    ///
+    /// # Returns
+    /// The number of input events read.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use std::mem::MaybeUninit;
+    /// use win32console::structs::input_record::InputRecord;
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::input_record::InputRecord::KeyEvent;
+    ///
+    /// let mut input_records : [InputRecord; 10] = unsafe { MaybeUninit::zeroed().assume_init() };
+    /// WinConsole::input().read_input(&mut input_records).unwrap();
+    ///
+    /// let mut buf = String::new();
+    /// for record in input_records.iter(){
+    ///     if let KeyEvent(key) = record{
+    ///         if key.key_down && key.u_char.is_ascii_alphanumeric(){
+    ///             buf.push(key.u_char);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// WinConsole::output().write_utf8(buf.as_bytes());
+    /// ```
+    pub fn read_input(&self, records: &mut [InputRecord]) -> Result<usize> {
+        if records.len() == 0 {
+            return Ok(0);
+        }
+
+        let mut buf = vec![unsafe { std::mem::zeroed::<INPUT_RECORD>() }; records.len()];
+        let num_events = self.read_input_raw(&mut buf)?;
+
+        // Copies each of the read events to the destination buffer
+        for (record, raw) in records.iter_mut().zip(&buf[..num_events]) {
+            *record = (*raw).into()
+        }
+
+        Ok(num_events)
+    }
+
+    // Fills the specified buffer with the raw `INPUT_RECORD` from the console,
+    // allows to reuse the buffer between reads as `InputReader` does.
+    pub(crate) fn read_input_raw(&self, buf: &mut [INPUT_RECORD]) -> Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let handle = self.get_handle();
+        let mut num_events = 0;
+
+        unsafe {
+            if ReadConsoleInputW(
+                **handle,
+                buf.as_mut_ptr(),
+                buf.len() as u32,
+                &mut num_events,
+            ) == 0
+            {
+                Err(Error::last_os_error())
+            } else {
+                // Documentation specify that at least 1 event will be read.
+                debug_assert!(num_events > 0);
+                Ok(num_events as usize)
+            }
+        }
+    }
+
+    /// Reads character and color attribute data from a rectangular block of character cells in a console screen buffer,
+    /// and the function writes the data to a rectangular block at a specified location in the destination buffer.
+    ///
+    /// Wraps a call to [ReadConsoleOutputW](https://docs.microsoft.com/en-us/windows/console/readconsoleoutput).
+    pub fn read_output(&self, buffer_size: Coord, buffer_coord: Coord, read_region: &mut SmallRect) -> Result<Vec<CharInfo>>{
+        let handle = self.get_handle();
+        let length = buffer_size.x as usize * buffer_size.y as usize;
+        let mut buffer = vec![unsafe{ std::mem::zeroed::<CHAR_INFO>() }; length];
+        let raw_rect = &mut (*read_region).into();
+
+        unsafe{
+            if ReadConsoleOutputW(
+                **handle,
+                buffer.as_mut_ptr(),
+                buffer_size.into(),
+                buffer_coord.into(),
+                raw_rect) == 0{
+                Err(Error::last_os_error())
+            }
+            else{
+                let ret = buffer.iter()
+                    .map(|c| (*c).into())
+                    .collect::<Vec<CharInfo>>();
+
+                *read_region = SmallRect::from(*raw_rect);
+                Ok(ret)
+            }
+        }
+    }
+
+    /// Reads the character and color attribute data of the whole console screen buffer,
+    /// including the rows outside the visible window, in row-major order.
+    ///
+    /// Wraps a call to [ReadConsoleOutputW](https://docs.microsoft.com/en-us/windows/console/readconsoleoutput).
+    ///
+    /// # Remarks
+    /// - `ReadConsoleOutputW` fails if the buffer is too large, so large buffers are read
+    ///   in chunks of rows using multiple calls.
+    /// - The whole screen buffer is copied, a buffer of 120x9001 cells takes more than 4MB of memory.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::coord::Coord;
+    ///
+    /// let old_size = WinConsole::output().get_screen_buffer_size().unwrap();
+    /// let window_size = WinConsole::output().get_window_size().unwrap();
+    /// let buffer_size = Coord::new(window_size.x, window_size.y * 10);
+    /// WinConsole::output().set_screen_buffer_size(buffer_size).unwrap();
+    ///
+    /// let buffer = WinConsole::output().read_full_buffer().unwrap();
+    /// assert_eq!(buffer_size.x as usize * buffer_size.y as usize, buffer.len());
+    ///
+    /// WinConsole::output().set_screen_buffer_size(old_size).unwrap();
+    /// ```
+    pub fn read_full_buffer(&self) -> Result<Vec<CharInfo>>{
+        let size = self.get_screen_buffer_size()?;
+        let width = size.x.max(0) as usize;
+        let height = size.y.max(0) as usize;
+
+        if width == 0 || height == 0 {
+            return Ok(Vec::new());
+        }
+
+        let rows_per_chunk = (WinConsole::MAX_OUTPUT_CELLS / width).max(1);
+        let mut buffer = Vec::with_capacity(width * height);
+        let mut top = 0;
+
+        while top < height {
+            let rows = rows_per_chunk.min(height - top);
+            let chunk_size = Coord::new(width as i16, rows as i16);
+            let mut read_region = SmallRect::new(0, top as i16, (width - 1) as i16, (top + rows - 1) as i16);
+
+            buffer.extend(self.read_output(chunk_size, Coord::ZERO, &mut read_region)?);
+            top += rows;
+        }
+
+        Ok(buffer)
+    }
+
+    /// Copies a specified number of character attributes from consecutive cells of a console screen buffer, beginning at a specified location.
+///
+/// Wraps a call to [ReadConsoleOutputAttribute](https://docs.microsoft.com/en-us/windows/console/readconsoleoutputattribute).
+///
+/// # Returns
+/// The number of attributes read.
+///
+/// # Errors
+/// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+/// the function should be called using `WinConsole::input()` or a valid input handle.
+///
+/// # Example
+/// ```
+/// use win32console::console::{WinConsole, ConsoleTextAttribute};
+/// use win32console::structs::console_color::ConsoleColor;
+/// use win32console::structs::coord::Coord;
+///
+/// fn write_color(data: &str, color: ConsoleColor){
+///     let c = WinConsole::output().get_foreground_color().unwrap();
+///     WinConsole::output().set_foreground_color(color);
+///     WinConsole::output().write_utf8(data.as_bytes());
+///     WinConsole::output().set_foreground_color(c);
+/// }
+///
+/// WinConsole::output().clear();
+/// write_color("R", ConsoleColor::DarkRed);
+/// write_color("G", ConsoleColor::DarkGreen);
+/// write_color("B", ConsoleColor::DarkBlue);
+///
+/// let mut buf = [0u16, 0u16, 0u16];
+/// let attributes_read = WinConsole::output().read_output_attribute(&mut buf, Coord::ZERO).unwrap();
+///
+/// assert_eq!(3, attributes_read);
+/// assert_eq!(ConsoleTextAttribute::FOREGROUND_RED, buf[0]);
+/// assert_eq!(ConsoleTextAttribute::FOREGROUND_GREEN, buf[1]);
+/// assert_eq!(ConsoleTextAttribute::FOREGROUND_BLUE, buf[2]);
+/// ```
+    pub fn read_output_attribute(&self, buffer: &mut [u16], read_coord: Coord) -> Result<usize>{
+        if buffer.len() == 0{
+            return Ok(0);
+        }
+
+        let handle = self.get_handle();
+
+        unsafe{
+            let mut attributes_read = 0;
+
+            if ReadConsoleOutputAttribute(**handle, buffer.as_mut_ptr(), buffer.len() as u32, read_coord.into(), &mut attributes_read) == 0{
+                Err(Error::last_os_error())
+            }
+            else{
+                Ok(attributes_read as usize)
+            }
+        }
+    }
+
+    /// Copies a number of characters from consecutive cells of a console screen buffer, beginning at a specified location.
+    ///
+    /// Wraps a call to [ReadConsoleOutputCharacterW](https://docs.microsoft.com/en-us/windows/console/readconsoleoutputcharacter).
+    ///
+    /// # Returns
+    /// The number of characters read.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::coord::Coord;
+    ///
+    /// WinConsole::output().clear();
+    ///
+    /// let data = b"Hola Mundo";
+    /// WinConsole::output().write_utf8(data);
+    /// let mut buf = vec![u8::default(); data.len()];
+    /// let chars_read = WinConsole::output().read_output_character(&mut buf, Coord::ZERO).expect("Unable to read");
+    ///
+    /// assert_eq!(chars_read, data.len());
+    /// assert_eq!(data, buf.as_slice());
+    /// ```
+    pub fn read_output_character(&self, buffer: &mut [u8], read_coord: Coord) -> Result<usize>{
+        if buffer.len() == 0{
+            return Ok(0);
+        }
+
+        let handle = self.get_handle();
+
+        unsafe{
+            let mut chars_read = 0;
+            let mut utf16_buffer = vec![u16::default(); buffer.len()];
+
+            if ReadConsoleOutputCharacterW(**handle, utf16_buffer.as_mut_ptr(), buffer.len() as u32, read_coord.into(), &mut chars_read) == 0{
+                Err(Error::last_os_error())
+            }
+            else{
+                WinConsole::utf16_to_utf8(&utf16_buffer, buffer)?;
+                Ok(chars_read as usize)
+            }
+        }
+    }
+
+    /// Captures the text of the visible window of the console as a `String`,
+    /// each row is separated by `\n` and the trailing spaces of each row are removed.
+    ///
+    /// This only captures the current window region, not the full screen buffer including the scrollback.
+    ///
+    /// Wraps a call to [ReadConsoleOutputCharacterW](https://docs.microsoft.com/en-us/windows/console/readconsoleoutputcharacter).
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// WinConsole::output().clear().unwrap();
+    /// WinConsole::output().write_utf8(b"Hello World!").unwrap();
+    ///
+    /// let snapshot = WinConsole::output().snapshot_text().unwrap();
+    /// assert!(snapshot.starts_with("Hello World!\n"));
+    /// ```
+    #[inline]
+    pub fn snapshot_text(&self) -> Result<String> {
+        Ok(self.rows()?.join("\n"))
+    }
+
+    /// Reads each row of the visible window of the console as a `String`,
+    /// the trailing spaces of each row are removed.
+    ///
+    /// This is the same text captured by [`snapshot_text`] but keeping each row separated.
+    ///
+    /// Wraps a call to [ReadConsoleOutputCharacterW](https://docs.microsoft.com/en-us/windows/console/readconsoleoutputcharacter).
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// WinConsole::output().clear().unwrap();
+    /// WinConsole::output().write_utf8(b"First\r\nSecond").unwrap();
+    ///
+    /// let rows = WinConsole::output().rows().unwrap();
+    /// assert_eq!(["First", "Second"], rows[..2]);
+    /// ```
+    ///
+    /// [`snapshot_text`]: #method.snapshot_text
+    pub fn rows(&self) -> Result<Vec<String>> {
+        let handle = self.get_handle();
+        let window = self.get_window_rect()?;
+        let width = window.width().max(0) as usize;
+
+        let mut rows = Vec::with_capacity(window.height().max(0) as usize);
+        let mut buffer = vec![u16::default(); width];
+
+        for y in window.top..=window.bottom {
+            let mut chars_read = 0;
+
+            unsafe {
+                if ReadConsoleOutputCharacterW(**handle, buffer.as_mut_ptr(), width as u32, Coord::new(window.left, y).into(), &mut chars_read) == 0 {
+                    return Err(Error::last_os_error());
+                }
+            }
+
+            let row = String::from_utf16_lossy(&buffer[..chars_read as usize]);
+            rows.push(row.trim_end_matches(' ').to_string());
+        }
+
+        Ok(rows)
+    }
+
+    /// Fills the specified buffer with the unread [`InputRecord`] from the console.
+    ///
+    /// # Returns
+    /// The number of input events read.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// Wraps a call to [PeekConsoleInputW](https://docs.microsoft.com/en-us/windows/console/peekconsoleinput).
+    ///
+    /// # Example
+    /// ```
+    /// use std::mem::MaybeUninit;
+    /// use win32console::structs::input_record::InputRecord;
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::input_record::InputRecord::KeyEvent;
+    ///
+    /// let mut input_records : [InputRecord; 10] = unsafe { MaybeUninit::zeroed().assume_init() };
+    /// WinConsole::input().peek_input(&mut input_records).unwrap();
+    ///
+    /// let mut buf = String::new();
+    /// for record in input_records.iter(){
+    ///     if let KeyEvent(key) = record{
+    ///         if key.key_down && key.u_char.is_ascii_alphanumeric(){
+    ///             buf.push(key.u_char);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// WinConsole::output().write_utf8(buf.as_bytes());
+    /// ```
+    pub fn peek_input(&self, records: &mut [InputRecord]) -> Result<usize> {
+        if records.len() == 0 {
+            return Ok(0);
+        }
+
+        let handle = self.get_handle();
+        let num_records = records.len();
+        let mut num_events = 0;
+
+        unsafe {
+            let mut buf = iter::repeat_with(|| std::mem::zeroed::<INPUT_RECORD>())
+                .take(num_records)
+                .collect::<Vec<INPUT_RECORD>>();
+
+            if PeekConsoleInputW(
+                **handle,
+                buf.as_mut_ptr(),
+                num_records as u32,
+                &mut num_events,
+            ) == 0
+            {
+                Err(Error::last_os_error())
+            } else {
+                // Copies each of the read events to the destination buffer
+                for i in 0..num_records {
+                    records[i] = buf[i].into()
+                }
+
+                Ok(num_events as usize)
+            }
+        }
+    }
+
+    /// Reads a `String` from the standard input, followed by a newline.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// WinConsole::output().write_utf8("What's your name? ".as_bytes());
+    /// let value = WinConsole::input().read_string().unwrap();
+    /// WinConsole::output().write_utf8(format!("Hello {}", value).as_bytes());
+    /// ```
+    pub fn read_string(&self) -> Result<String> {
+        // Used buffer size from:
+        // https://source.dot.net/#System.Console/System/Console.cs,dac049f8d10df4a0
+        const MAX_BUFFER_SIZE: usize = 4096;
+
+        let mut buffer: [u16; MAX_BUFFER_SIZE] = unsafe { MaybeUninit::zeroed().assume_init() };
+        let chars_read = self.read_utf16(&mut buffer)?;
+
+        match String::from_utf16(buffer[..chars_read].as_ref()) {
+            Ok(string) => Ok(string),
+            Err(e) => Err(Error::new(ErrorKind::InvalidData, e)),
+        }
+    }
+
+    /// Reads a line from the standard input and appends it to the given `String`,
+    /// this allows reuse the same `String` for multiple reads.
+    ///
+    /// The line is appended including the newline, if is not needed can be remove using
+    /// [`str::trim_end`] over the appended content.
+    ///
+    /// If the input is being redirected the bytes are read until a `\n` or the end of the input.
+    ///
+    /// # Returns
+    /// The number of bytes appended to the `String`.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    ///   the function should be called using `WinConsole::input()` or a valid input handle.
+    /// - If the data read is not valid unicode.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// let mut line = String::new();
+    /// loop{
+    ///     line.clear();
+    ///     WinConsole::output().write_utf8(b"> ").unwrap();
+    ///     WinConsole::input().read_line_into(&mut line).unwrap();
+    ///
+    ///     if line.trim_end() == "exit"{
+    ///         break;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`str::trim_end`]: https://doc.rust-lang.org/std/primitive.str.html#method.trim_end
+    pub fn read_line_into(&self, buf: &mut String) -> Result<usize> {
+        let handle = self.get_handle();
+
+        // If is being redirected read byte by byte to not consume the next line
+        if !WinConsole::is_console(handle) {
+            let mut bytes = Vec::new();
+            let mut byte = 0_u8;
+
+            loop {
+                let mut bytes_read = 0;
+                unsafe {
+                    if ReadFile(
+                        **handle,
+                        &mut byte as *mut u8 as *mut c_void,
+                        1,
+                        &mut bytes_read,
+                        null_mut(),
+                    ) == 0
+                    {
+                        return Err(Error::last_os_error());
+                    }
+                }
+
+                if bytes_read == 0 {
+                    break;
+                }
+
+                bytes.push(byte);
+
+                if byte == b'\n' {
+                    break;
+                }
+            }
+
+            return match str::from_utf8(&bytes) {
+                Ok(string) => {
+                    buf.push_str(string);
+                    Ok(bytes.len())
+                }
+                Err(e) => Err(Error::new(ErrorKind::InvalidData, e)),
+            };
+        }
+
+        const MAX_BUFFER_SIZE: usize = 4096;
+
+        let mut buffer = [0_u16; MAX_BUFFER_SIZE];
+        let chars_read = self.read_utf16(&mut buffer)?;
+        let old_len = buf.len();
+
+        for chr in std::char::decode_utf16(buffer[..chars_read].iter().cloned()) {
+            match chr {
+                Ok(value) => buf.push(value),
+                Err(e) => {
+                    buf.truncate(old_len);
+                    return Err(Error::new(ErrorKind::InvalidData, e));
+                }
+            }
+        }
+
+        Ok(buf.len() - old_len)
+    }
+
+    /// Reads a line from the console input with basic line editing, by reading the key events
+    /// and echoing the characters to `WinConsole::output()`, useful when the `ENABLE_LINE_INPUT`
+    /// mode is disabled and the console does not provide the line editing.
+    ///
+    /// - The printable characters are appended to the line and written to the output.
+    /// - `Backspace` removes the last character of the line and erases it from the output.
+    /// - `Enter` ends the line and writes a `\r\n`.
+    ///
+    /// Any other key is ignored.
+    ///
+    /// Wraps a call to [ReadConsoleInputW](https://docs.microsoft.com/en-us/windows/console/readconsoleinput).
+    ///
+    /// # Returns
+    /// The line read without the newline.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    ///   the function should be called using `WinConsole::input()` or a valid input handle.
+    /// - If the characters cannot be written to the output.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::{WinConsole, ConsoleMode};
+    ///
+    /// let old_mode = WinConsole::input().get_mode().unwrap();
+    /// WinConsole::input().set_mode(old_mode & !(ConsoleMode::ENABLE_LINE_INPUT | ConsoleMode::ENABLE_ECHO_INPUT)).unwrap();
+    ///
+    /// WinConsole::output().write_utf8(b"What's your name? ").unwrap();
+    /// let name = WinConsole::input().read_line_edited().unwrap();
+    /// WinConsole::output().write_utf8(format!("Hello {}!", name).as_bytes()).unwrap();
+    ///
+    /// WinConsole::input().set_mode(old_mode).unwrap();
+    /// ```
+    pub fn read_line_edited(&self) -> Result<String> {
+        let output = WinConsole::output();
+        let mut line = String::new();
+
+        loop {
+            let key = match self.read_single_input()? {
+                InputRecord::KeyEvent(key) if key.key_down => key,
+                _ => continue,
+            };
+
+            for _ in 0..key.repeat_count.max(1) {
+                match key.virtual_key_code as i32 {
+                    VK_RETURN => {
+                        output.write_utf8(b"\r\n")?;
+                        return Ok(line);
+                    }
+                    VK_BACK if !line.is_empty() => {
+                        line.pop();
+                        output.write_utf8(b"\x08 \x08")?;
+                    }
+                    VK_BACK => {}
+                    _ if !key.u_char.is_control() => {
+                        output.write_char(key.u_char)?;
+                        line.push(key.u_char);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Fills the given `u8` buffer with characters from the standard input.
+    ///
+    /// # Returns
+    /// The number of characters read.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use std::mem::MaybeUninit;
+    /// use win32console::console::WinConsole;
+    /// let mut buffer : [u8 ; 10] = unsafe { MaybeUninit::zeroed().assume_init() };
+    /// WinConsole::input().read_utf8(&mut buffer);
+    /// ```
+    pub fn read_utf8(&self, buffer: &mut [u8]) -> Result<usize> {
+        if buffer.len() == 0 {
+            return Ok(0);
+        }
+
+        let mut utf16_buffer = vec![u16::default(); buffer.len()];
+
+        // Writes the read data to the 'utf16_buffer'.
+        self.read_utf16(&mut utf16_buffer)?;
+        let written = WinConsole::utf16_to_utf8(&utf16_buffer, buffer)?;
+        Ok(written)
+    }
+
+    /// Fills the given `u16` buffer with characters from the standard input.
+    ///
+    /// Wraps a call to [ReadConsoleW](https://docs.microsoft.com/en-us/windows/console/readconsole).
+    ///
+    /// # Returns
+    /// The number of characters read.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use std::mem::MaybeUninit;
+    /// use win32console::console::WinConsole;
+    /// let mut buffer : [u16 ; 10] = unsafe { MaybeUninit::zeroed().assume_init() };
+    /// WinConsole::input().read_utf16(&mut buffer);
+    /// ```
+    pub fn read_utf16(&self, buffer: &mut [u16]) -> Result<usize> {
+        if buffer.len() == 0 {
+            return Ok(0);
+        }
+
+        // https://github.com/rust-lang/rust/blob/master/src/libstd/sys/windows/stdio.rs
+        // https://stackoverflow.com/questions/43836040/win-api-readconsole
+        const CTRL_Z: u16 = 0x1A;
+        const CTRL_Z_MASK: u32 = (1 << CTRL_Z) as u32;
+
+        let mut input_control = CONSOLE_READCONSOLE_CONTROL {
+            nLength: std::mem::size_of::<CONSOLE_READCONSOLE_CONTROL>() as u32,
+            nInitialChars: 0,
+            dwCtrlWakeupMask: CTRL_Z_MASK,
+            dwControlKeyState: 0,
+        };
+
+        let handle = self.get_handle();
+        let mut chars_read = 0;
+
+        if !WinConsole::is_console(&handle) {
+            let mut data = match String::from_utf16(buffer) {
+                Ok(string) => string,
+                Err(e) => return Err(Error::new(std::io::ErrorKind::InvalidInput, e)),
+            };
+
+            unsafe {
+                if ReadFile(
+                    **handle,
+                    data.as_mut_ptr() as *mut c_void,
+                    buffer.len() as u32,
+                    &mut chars_read,
+                    null_mut(),
+                ) == 0
+                {
+                    return Err(Error::last_os_error());
+                }
+            }
+
+            return Ok(chars_read as usize);
+        }
+
+        unsafe {
+            if ReadConsoleW(
+                **handle,
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer.len() as u32,
+                &mut chars_read,
+                &mut input_control,
+            ) == 0
+            {
+                Err(Error::last_os_error())
+            } else {
+                if chars_read > 0 && buffer[chars_read as usize - 1] == CTRL_Z {
+                    chars_read -= 1;
+                }
+
+                Ok(chars_read as usize)
+            }
+        }
+    }
+
+    /// Fills the given `u8` buffer with characters from the standard input using the specified
+    /// console read control.
+    ///
+    /// - `control`: provides information used for a read operation as the number of chars
+    /// to skip or the end signal.
+    ///
+    /// Wraps a call to [ReadConsoleA](https://docs.microsoft.com/en-us/windows/console/readconsole).
+    ///
+    /// # Returns
+    /// The number of characters read.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use std::mem::MaybeUninit;
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::console_read_control::ConsoleReadControl;
+    ///
+    /// const CTRL_Z: u8 = 26;
+    /// const CTRL_Z_MASK: u32 = (1 << CTRL_Z) as u32;
+    ///
+    /// let control = ConsoleReadControl::new_with_mask(CTRL_Z_MASK);
+    /// let mut buffer : [u8 ; 32] = unsafe { MaybeUninit::zeroed().assume_init() };
+    /// let mut len = WinConsole::input().read_utf8_with_control(&mut buffer, control).unwrap();
+    ///
+    /// // If the last character is the control signal we ignore it.
+    /// if len > 0 && buffer[len - 1] == CTRL_Z{
+    ///     len -= 1;
+    /// }
+    ///
+    /// let string = String::from_utf8_lossy(&buffer[..len])
+    ///                     .trim() // String terminated in newline
+    ///                     .to_string();
+    ///
+    /// // buffer is terminated in '\r\n', assertion will fail when write 32 characters
+    /// assert_eq!(len - 2, string.len());
+    /// WinConsole::output().write_utf8(string.as_bytes());
+    /// ```
+    pub fn read_utf8_with_control(
+        &self,
+        buffer: &mut [u8],
+        control: ConsoleReadControl,
+    ) -> Result<usize> {
+        if buffer.len() == 0 {
+            return Ok(0);
+        }
+
+        let mut utf16_buffer = vec![u16::default(); buffer.len()];
+        let written = self.read_utf16_with_control(utf16_buffer.as_mut_slice(), control)?;
+        WinConsole::utf16_to_utf8(&utf16_buffer, buffer)?;
+        Ok(written)
+    }
+
+    /// Fills the given `u16` buffer with characters from the standard input using the specified
+    /// console read control.
+    ///
+    /// - `control`: provides information used for a read operation as the number of chars
+    /// to skip or the end signal.
+    ///
+    /// Wraps a call to [ReadConsoleW](https://docs.microsoft.com/en-us/windows/console/readconsole).
+    ///
+    /// # Returns
+    /// The number of characters read.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use std::mem::MaybeUninit;
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::console_read_control::ConsoleReadControl;
+    ///
+    /// const CTRL_Z: u16 = 26;
+    /// const CTRL_Z_MASK: u32 = (1 << CTRL_Z) as u32;
+    ///
+    /// let control = ConsoleReadControl::new_with_mask(CTRL_Z_MASK);
+    /// let mut buffer : [u16 ; 32] = unsafe { MaybeUninit::zeroed().assume_init() };
+    /// let mut len = WinConsole::input().read_utf16_with_control(&mut buffer, control).unwrap();
+    ///
+    /// // If the last character is the control signal we ignore it.
+    /// if len > 0 && buffer[len - 1] == CTRL_Z{
+    ///     len -= 1;
+    /// }
+    ///
+    /// let string = String::from_utf16_lossy(&buffer[..len])
+    ///                     .trim() // String terminated in newline
+    ///                     .to_string();
+    ///
+    /// // buffer is terminated in '\r\n', assertion will fail when write 32 characters
+    /// assert_eq!(len - 2, string.len());
+    /// WinConsole::output().write_utf8(string.as_bytes());
+    /// ```
+    pub fn read_utf16_with_control(
+        &self,
+        buffer: &mut [u16],
+        control: ConsoleReadControl,
+    ) -> Result<usize> {
+        if buffer.len() == 0 {
+            return Ok(0);
+        }
+
+        let mut input_control = control.into();
+        let handle = self.get_handle();
+        let mut chars_read = 0;
+
+        if !WinConsole::is_console(&handle) {
+            let mut data = match String::from_utf16(buffer) {
+                Ok(string) => string,
+                Err(e) => return Err(Error::new(std::io::ErrorKind::InvalidInput, e)),
+            };
+
+            unsafe {
+                if ReadFile(
+                    **handle,
+                    data.as_mut_ptr() as *mut c_void,
+                    buffer.len() as u32,
+                    &mut chars_read,
+                    null_mut(),
+                ) == 0
+                {
+                    return Err(Error::last_os_error());
+                }
+            }
+
+            return Ok(chars_read as usize);
+        }
+
+        unsafe {
+            if ReadConsoleW(
+                **handle,
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer.len() as u32,
+                &mut chars_read,
+                &mut input_control,
+            ) == 0
+            {
+                Err(Error::last_os_error())
+            } else {
+                Ok(chars_read as usize)
+            }
+        }
+    }
+
+    /// Reads a line from the console input with the given prefix as editable default input,
+    /// the prefix is written to the active screen buffer of the console, where the input is echoed,
+    /// and the user can edit it as if was typed.
+    ///
+    /// The prefix is preserved by setting it as the `initial_chars` of the [`ConsoleReadControl`]
+    /// used for the read, so is returned as part of the line.
+    ///
+    /// If the input is being redirected the prefix is not written and the line read is appended to it.
+    ///
+    /// Wraps a call to [ReadConsoleW](https://docs.microsoft.com/en-us/windows/console/readconsole).
+    ///
+    /// # Returns
+    /// The line read including the prefix, without the newline.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    ///   the function should be called using `WinConsole::input()` or a valid input handle.
+    /// - `ErrorKind::InvalidInput` if the prefix is too long to fit in the read buffer.
+    /// - If the data read is not valid unicode.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// WinConsole::output().write_utf8(b"Save as: ").unwrap();
+    /// let file_name = WinConsole::input().read_with_prefix("untitled.txt").unwrap();
+    /// WinConsole::output().write_utf8(format!("Saved as {}", file_name).as_bytes()).unwrap();
+    /// ```
+    ///
+    /// [`ConsoleReadControl`]: ../structs/console_read_control/struct.ConsoleReadControl.html
+    pub fn read_with_prefix(&self, prefix: &str) -> Result<String> {
+        // Used buffer size from:
+        // https://source.dot.net/#System.Console/System/Console.cs,dac049f8d10df4a0
+        const MAX_BUFFER_SIZE: usize = 4096;
+
+        let mut line = if WinConsole::is_console(self.get_handle()) {
+            let mut buffer = prefix.encode_utf16().collect::<Vec<u16>>();
+            let initial_chars = buffer.len();
+
+            // Keeps space for the line ending
+            if initial_chars > MAX_BUFFER_SIZE - 2 {
+                return Err(Error::new(ErrorKind::InvalidInput, "the prefix is too long"));
+            }
+
+            buffer.resize(MAX_BUFFER_SIZE, 0);
+
+            // `CONOUT$` is the active screen buffer of the console that owns this input handle,
+            // which is used even if the standard output is redirected
+            let output = WinConsole::with_handle(WinConsole::get_current_output_handle()?);
+            output.write_all_utf8(prefix.as_bytes())?;
+
+            let control = ConsoleReadControl::new(initial_chars as u32, 0, ControlKeyState::none());
+            let chars_read = self.read_utf16_with_control(&mut buffer, control)?;
+
+            match String::from_utf16(&buffer[..chars_read]) {
+                Ok(string) => string,
+                Err(e) => return Err(Error::new(ErrorKind::InvalidData, e)),
+            }
+        } else {
+            let mut line = String::from(prefix);
+            self.read_line_into(&mut line)?;
+            line
+        };
+
+        let len = line.trim_end_matches(&['\r', '\n'][..]).len();
+        line.truncate(len);
+        Ok(line)
+    }
+
+    /// Flushes the console input buffer. All input records currently in the input buffer are discarded.
+    ///
+    /// Wraps a call to [FlushConsoleInputBuffer](https://docs.microsoft.com/en-us/windows/console/flushconsoleinputbuffer).
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// WinConsole::input().flush_input();
+    /// ```
+    pub fn flush_input(&self) -> Result<()>{
+        let handle = self.get_handle();
+
+        unsafe {
+            if FlushConsoleInputBuffer(**handle) == 0{
+                Err(Error::last_os_error())
+            }
+            else{
+                Ok(())
+            }
+        }
+    }
+
+    /// Writes the specified `u8` buffer of chars in the current cursor position of the console.
+    ///
+    /// The bytes are decoded as `UTF-8` and written as `UTF-16`, so the text is displayed
+    /// correctly regardless of the console code page.
+    ///
+    /// Wraps a call to [WriteConsoleW](https://docs.microsoft.com/en-us/windows/console/writeconsole).
+    ///
+    /// # Returns
+    /// The number of bytes written. If the data ends in the middle of a character only the complete
+    /// characters are written, so the remaining bytes can be written again along with the rest of the character.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - `ErrorKind::InvalidInput` if the data is not valid `UTF-8` or only contains an incomplete character,
+    ///   the message contains the byte offset of the first invalid sequence. The data is validated the same
+    ///   way when the output is redirected, and nothing is written if is invalid.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// WinConsole::output().write_utf8("Hello World!".as_bytes());
+    /// ```
+    pub fn write_utf8(&self, data: &[u8]) -> Result<usize> {
+        if data.len() == 0 {
+            return Ok(0);
+        }
+
+        // The data is validated before writing to either a console or a redirected handle,
+        // an incomplete character at the end is left to be written in the next call
+        let text = match std::str::from_utf8(data) {
+            Ok(text) => text,
+            Err(e) if e.error_len().is_none() && e.valid_up_to() > 0 => {
+                std::str::from_utf8(&data[..e.valid_up_to()]).unwrap()
+            }
+            Err(e) => return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid UTF-8 at byte offset {}: {}", e.valid_up_to(), e))),
+        };
+
+        let handle = self.get_handle();
+        let mut chars_written = 0;
+
+        // If is being redirected write to the handle
+        if !WinConsole::is_console(&handle) {
+            unsafe {
+                if WriteFile(
+                    **handle,
+                    text.as_ptr() as *const c_void,
+                    text.len() as u32,
+                    &mut chars_written,
+                    null_mut(),
+                ) == 0
+                {
+                    return Err(Error::last_os_error());
+                }
+            }
+            return Ok(chars_written as usize);
+        }
+
+        let utf16_buffer = text.encode_utf16().collect::<Vec<u16>>();
+
+        unsafe {
+            if WriteConsoleW(
+                **handle,
+                utf16_buffer.as_ptr() as *const c_void,
+                utf16_buffer.len() as u32,
+                &mut chars_written,
+                null_mut(),
+            ) == 0
+            {
+                return Err(Error::last_os_error());
+            }
+        }
+
+        if chars_written as usize >= utf16_buffer.len() {
+            return Ok(text.len());
+        }
+
+        // Counts the bytes of the characters that were written
+        let mut units = 0;
+        let bytes_written = text.chars()
+            .take_while(|c| {
+                units += c.len_utf16();
+                units <= chars_written as usize
+            })
+            .map(char::len_utf8)
+            .sum();
+
+        Ok(bytes_written)
+    }
+
+    /// Writes all the specified `u8` buffer of chars in the current cursor position of the console,
+    /// calling [`write_utf8`] until all the bytes are written or an error occurs.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - `ErrorKind::WriteZero` if no bytes could be written.
+    /// - `ErrorKind::InvalidInput` if the data is not valid `UTF-8` or ends with an incomplete character,
+    ///   the complete characters before it are already written.
+    ///
+    /// # Example
+    /// ```
+    /// use std::fs::File;
+    /// use std::os::windows::io::AsRawHandle;
+    /// use win32console::console::{WinConsole, HandleType};
+    /// use win32console::structs::handle::Handle;
+    ///
+    /// let data = "Hello World!\n".repeat(10_000);
+    ///
+    /// // Redirects the output to a file
+    /// let path = std::env::temp_dir().join("write_all.txt");
+    /// let std_output = WinConsole::get_std_handle(HandleType::Output).unwrap();
+    /// let file = File::create(&path).unwrap();
+    /// WinConsole::set_std_handle(HandleType::Output, Handle::new(file.as_raw_handle() as _)).unwrap();
+    /// WinConsole::output().write_all_utf8(data.as_bytes()).unwrap();
+    /// WinConsole::set_std_handle(HandleType::Output, std_output).unwrap();
+    ///
+    /// assert_eq!(data, std::fs::read_to_string(&path).unwrap());
+    ///
+    /// drop(file);
+    /// std::fs::remove_file(path).unwrap();
+    /// ```
+    ///
+    /// [`write_utf8`]: #method.write_utf8
+    pub fn write_all_utf8(&self, data: &[u8]) -> Result<()> {
+        let mut written = 0;
+
+        while written < data.len() {
+            match self.write_utf8(&data[written..])? {
+                0 => return Err(Error::new(ErrorKind::WriteZero, "failed to write the whole buffer")),
+                n => written += n,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes all the given bytes to the console and returns the position of the cursor after writing,
+    /// useful to know where the output ended to place the next elements.
+    ///
+    /// The bytes are written using [`write_all_utf8`] and then the cursor position is read
+    /// using [`get_cursor_position`].
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - If the output is redirected to a file, because there is no cursor to read.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// let start = WinConsole::output().get_cursor_position().unwrap();
+    /// let end = WinConsole::output().write_utf8_tracked("Name: ".as_bytes()).unwrap();
+    /// assert_eq!(start.y, end.y);
+    /// ```
+    ///
+    /// [`write_all_utf8`]: #method.write_all_utf8
+    /// [`get_cursor_position`]: #method.get_cursor_position
+    pub fn write_utf8_tracked(&self, data: &[u8]) -> Result<Coord> {
+        self.write_all_utf8(data)?;
+        self.get_cursor_position()
+    }
+
+    /// Flushes the data written to the output.
+    ///
+    /// The writes to a console are not buffered so this does nothing for a console handle,
+    /// but if the output is redirected to a file or a pipe, ensures the data written is persisted.
+    ///
+    /// Wraps a call to [FlushFileBuffers](https://docs.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-flushfilebuffers).
+    ///
+    /// # Errors
+    /// - If the output is redirected and the handle cannot be flushed.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// WinConsole::output().write_utf8(b"Progress: 100%").unwrap();
+    /// WinConsole::output().flush_output().unwrap();
+    /// ```
+    pub fn flush_output(&self) -> Result<()> {
+        let handle = self.get_handle();
+
+        if WinConsole::is_console(handle) {
+            return Ok(());
+        }
+
+        unsafe {
+            if FlushFileBuffers(**handle) == 0 {
+                Err(Error::last_os_error())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    /// Writes each of the given lines followed by a `\r\n` line ending,
+    /// so the cursor is left at the start of the next line.
+    ///
+    /// The `\r\n` line ending moves the cursor to the start of the next line even when
+    /// the `DISABLE_NEWLINE_AUTO_RETURN` mode is set, and is the line ending used in
+    /// Windows when the output is redirected to a file.
+    ///
+    /// # Remarks
+    /// - The lines are written with a single write operation.
+    /// - Any newline inside a line is written as is, without being converted to `\r\n`.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// WinConsole::output().write_lines(&["First line", "Second line", "Third line"]).unwrap();
+    /// ```
+    pub fn write_lines(&self, lines: &[&str]) -> Result<()> {
+        let mut text = String::with_capacity(lines.iter().map(|line| line.len() + 2).sum());
+
+        for line in lines {
+            text.push_str(line);
+            text.push_str("\r\n");
+        }
+
+        self.write_all_utf8(text.as_bytes())
+    }
+
+    /// Writes the specified `char` in the current cursor position of the console.
+    ///
+    /// # Returns
+    /// The number of bytes of the `UTF-8` encoded `char` that were written.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// // Non-ASCII chars are written with all its bytes
+    /// let written = WinConsole::output().write_char('ñ').unwrap();
+    /// assert_eq!('ñ'.len_utf8(), written);
+    /// ```
+    pub fn write_char(&self, c: char) -> Result<usize> {
+        let mut buf = [0u8; 4];
+        let encoded = c.encode_utf8(&mut buf);
+        self.write_utf8(encoded.as_bytes())
+    }
+
+    /// Writes the specified text that may contain ANSI escape sequences in the current cursor position of the console.
+    ///
+    /// If the handle is a console handle with the `ENABLE_VIRTUAL_TERMINAL_PROCESSING` mode enabled
+    /// the text is written as is, otherwise if the console don't process the virtual terminal sequences
+    /// or the output is being redirected to a file or a pipe the CSI sequences like `\x1b[31m`
+    /// are removed before write the text, any other byte is left untouched.
+    ///
+    /// # Returns
+    /// The number of bytes written, when the escape sequences are removed this is the length of the text without them.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use std::fs::File;
+    /// use std::os::windows::io::AsRawHandle;
+    /// use win32console::console::{WinConsole, HandleType};
+    /// use win32console::structs::handle::Handle;
+    ///
+    /// // Writes to the console, the escape sequences are only removed if the console don't process them
+    /// WinConsole::output().write_ansi("\x1b[31mRed\x1b[0m").unwrap();
+    ///
+    /// // Writes to a file without the escape sequences
+    /// let path = std::env::temp_dir().join("ansi.txt");
+    /// let std_output = WinConsole::get_std_handle(HandleType::Output).unwrap();
+    /// let file = File::create(&path).unwrap();
+    /// WinConsole::set_std_handle(HandleType::Output, Handle::new(file.as_raw_handle() as _)).unwrap();
+    /// let written = WinConsole::output().write_ansi("\x1b[31mRed\x1b[0m").unwrap();
+    /// WinConsole::set_std_handle(HandleType::Output, std_output).unwrap();
+    ///
+    /// assert_eq!(3, written);
+    /// assert_eq!("Red", std::fs::read_to_string(&path).unwrap());
+    ///
+    /// drop(file);
+    /// std::fs::remove_file(path).unwrap();
+    /// ```
+    pub fn write_ansi(&self, text: &str) -> Result<usize> {
+        // Fails if the output is redirected, in that case the sequences are also removed
+        let virtual_terminal = self.get_mode()
+            .map(|mode| mode & ConsoleMode::ENABLE_VIRTUAL_TERMINAL_PROCESSING != 0)
+            .unwrap_or(false);
+
+        if virtual_terminal {
+            self.write_utf8(text.as_bytes())
+        } else {
+            let stripped = WinConsole::strip_ansi_sequences(text);
+            self.write_utf8(stripped.as_bytes())
+        }
+    }
+
+    /// Writes each of the specified `(text, attribute)` segments in the current cursor position of the console,
+    /// the text attribute is only changed when differs from the attribute of the previous segment.
+    ///
+    /// After write all the segments the previous text attribute is restored.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::{WinConsole, ConsoleTextAttribute};
+    ///
+    /// let red = ConsoleTextAttribute::FOREGROUND_RED | ConsoleTextAttribute::FOREGROUND_INTENSITY;
+    /// let green = ConsoleTextAttribute::FOREGROUND_GREEN;
+    ///
+    /// // Only 2 attributes changes are made
+    /// WinConsole::output().write_styled(&[("Error", red), (": ", red), ("everything is fine", green)]).unwrap();
+    /// ```
+    pub fn write_styled(&self, segments: &[(&str, u16)]) -> Result<()> {
+        let old_attribute = self.get_text_attribute()?;
+        let mut current_attribute = old_attribute;
+
+        for (text, attribute) in WinConsole::group_styled_segments(segments) {
+            if attribute != current_attribute {
+                self.set_text_attribute(attribute)?;
+                current_attribute = attribute;
+            }
+
+            self.write_utf8(text.as_bytes())?;
+        }
+
+        if current_attribute != old_attribute {
+            self.set_text_attribute(old_attribute)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the specified buffer of chars in the current cursor position of the console.
+    ///
+    /// Wraps a call to [WriteConsoleW](https://docs.microsoft.com/en-us/windows/console/writeconsole).
+    ///
+    /// # Returns
+    /// The number of characters written.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// let x = "Hello World!".encode_utf16().collect::<Vec<u16>>();
+    /// WinConsole::output().write_utf16(x.as_slice());
+    /// ```
+    pub fn write_utf16(&self, data: &[u16]) -> Result<usize> {
+        if data.len() == 0 {
+            return Ok(0);
+        }
+
+        let handle = self.get_handle();
+        let mut chars_written = 0;
+
+        // If is being redirected write to the handle
+        if !WinConsole::is_console(&handle) {
+            let buf = match String::from_utf16(data) {
+                Ok(string) => string,
+                Err(e) => return Err(Error::new(std::io::ErrorKind::InvalidInput, e)),
+            };
+
+            unsafe {
+                if WriteFile(
+                    **handle,
+                    buf.as_ptr() as *const c_void,
+                    data.len() as u32,
+                    &mut chars_written,
+                    null_mut(),
+                ) == 0
+                {
+                    return Err(Error::last_os_error());
+                }
+            }
+            return Ok(data.len());
+        }
+
+        unsafe {
+            if WriteConsoleW(
+                **handle,
+                data.as_ptr() as *const c_void,
+                data.len() as u32,
+                &mut chars_written,
+                null_mut(),
+            ) == 0
+            {
+                Err(Error::last_os_error())
+            } else {
+                assert_eq!(chars_written, data.len() as u32);
+                Ok(chars_written as usize)
+            }
+        }
+    }
+
+    /// Writes the given buffer of `CharInfo` into the screen buffer.
+    ///
+    /// Wraps a call to [WriteConsoleOutputW](https://docs.microsoft.com/en-us/windows/console/writeconsoleoutput).
+    ///
+    /// See also: [`https://www.randygaul.net/2011/11/16/windows-console-game-writing-to-the-console/`]
+    ///
+    /// - `buffer_size`: the size of the `buffer` in rows and columns.
+    /// - `buffer_start`: the origin in the `buffer` where start to take the characters to write, typically (0,0).
+    /// - `write_area`: Represents the screen buffer area to write to.
+    ///
+    /// # Remarks
+    /// - This functions don't affect the cursor position.
+    /// - If the `write_area` is outside the screen buffer no data is written.
+    /// - Large areas are written in horizontal strips using multiple calls to `WriteConsoleOutputW`,
+    ///   due a single call fails if the buffer is too large.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - `ErrorKind::InvalidInput` if the `buffer` length is less than the `buffer_size`.
+    /// - `ErrorKind::Unsupported` if the output is redirected to a file or pipe,
+    ///   the cells can only be written to a console screen buffer.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::coord::Coord;
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::char_info::CharInfo;
+    /// use win32console::structs::small_rect::SmallRect;
+    /// const WIDTH : usize = 40;
+    /// const HEIGHT : usize = 30;
+    ///
+    /// let mut buffer = Vec::with_capacity(WIDTH * HEIGHT);
+    /// let buffer_size = Coord::new(WIDTH as i16, HEIGHT as i16);
+    /// let window = SmallRect::new(0, 0, (WIDTH - 1) as i16, (HEIGHT - 1) as i16);
+    ///
+    /// WinConsole::output().set_window_info(true, &window).unwrap();
+    /// WinConsole::output().set_screen_buffer_size(buffer_size.clone()).unwrap();
+    ///
+    /// for i in 0..buffer.capacity(){
+    ///    let char_info = CharInfo::new(' ', (16 << i % 3) as u16);
+    ///     buffer.push(char_info);
+    /// }
+    ///
+    /// WinConsole::output().write_output(buffer.as_ref(), buffer_size, Coord::ZERO, window).unwrap();
+    /// ```
+    ///
+    /// Writing an area larger than the limit of a single call:
+    /// ```
+    /// use win32console::structs::coord::Coord;
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::char_info::CharInfo;
+    /// use win32console::structs::small_rect::SmallRect;
+    ///
+    /// let old_size = WinConsole::output().get_screen_buffer_size().unwrap();
+    /// let buffer_size = Coord::new(200, 100);
+    /// WinConsole::output().set_screen_buffer_size(buffer_size).unwrap();
+    ///
+    /// let buffer = vec![CharInfo::new('#', 0x2F); 200 * 100];
+    /// WinConsole::output().write_output(&buffer, buffer_size, Coord::ZERO, SmallRect::new(0, 0, 199, 99)).unwrap();
+    ///
+    /// let mut read_region = SmallRect::new(0, 99, 199, 99);
+    /// let last_row = WinConsole::output().read_output(Coord::new(200, 1), Coord::ZERO, &mut read_region).unwrap();
+    /// assert!(last_row.iter().all(|c| *c == CharInfo::new('#', 0x2F)));
+    ///
+    /// WinConsole::output().set_screen_buffer_size(old_size).unwrap();
+    /// ```
+    pub fn write_output(
+        &self,
+        buffer: &[CharInfo],
+        buffer_size: Coord,
+        buffer_start: Coord,
+        write_area: SmallRect,
+    ) -> Result<()> {
+        if buffer.is_empty() {
+            return Ok(());
+        }
+
+        let width = buffer_size.x.max(0) as usize;
+        let height = buffer_size.y.max(0) as usize;
+
+        if buffer.len() < width * height {
+            return Err(Error::new(ErrorKind::InvalidInput, "the buffer is smaller than the buffer size"));
+        }
+
+        let handle = self.get_handle();
+
+        // Cell based writes are only supported by the console, not by files or pipes,
+        // the invalid handles are left to fail with the OS error
+        let info = self.describe_handle();
+        if info.is_valid && !info.is_console {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "writing cells requires a console screen buffer, but the output is redirected"));
+        }
+
+        let buf = buffer
+            .iter()
+            .map(|c| (*c).into())
+            .collect::<Vec<CHAR_INFO>>();
+
+        // Writes the area in strips of rows, each one taken from the rows of the buffer
+        let rows_per_chunk = (WinConsole::MAX_OUTPUT_CELLS / width.max(1)).max(1) as i16;
+        let total_rows = write_area.bottom - write_area.top + 1;
+        let mut offset = 0;
+
+        while offset < total_rows {
+            let buffer_row = buffer_start.y + offset;
+            let rows = rows_per_chunk
+                .min(total_rows - offset)
+                .min(buffer_size.y - buffer_row);
+
+            // There is no more rows in the buffer to write
+            if rows <= 0 {
+                break;
+            }
+
+            let chunk = &buf[(buffer_row.max(0) as usize * width)..];
+            let write_area_raw: PSMALL_RECT = &mut SmallRect::new(
+                write_area.left,
+                write_area.top + offset,
+                write_area.right,
+                write_area.top + offset + rows - 1
+            ).into();
+
+            unsafe {
+                if WriteConsoleOutputW(
+                    **handle,
+                    chunk.as_ptr() as PCHAR_INFO,
+                    Coord::new(buffer_size.x, rows).into(),
+                    Coord::new(buffer_start.x, 0).into(),
+                    write_area_raw,
+                ) == 0
+                {
+                    return Err(Error::last_os_error());
+                }
+            }
+
+            offset += rows;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the cells of the given iterator into the specified region of the screen buffer,
+    /// the cells fill the `region` row by row, from left to right.
+    ///
+    /// This is the same as [`write_output`] but without the need to collect the cells
+    /// into a buffer of the exact size of the region.
+    ///
+    /// - `cells`: the cells to write, must yield exactly `region.width() * region.height()` items,
+    ///   at most one item more than that is taken so infinite iterators are also rejected.
+    /// - `region`: the area of the screen buffer to write to.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - `ErrorKind::InvalidInput` if the number of cells don't match the area of the `region`.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::char_info::CharInfo;
+    /// use win32console::structs::small_rect::SmallRect;
+    ///
+    /// // A 10x5 checkerboard
+    /// let cells = (0..50).map(|i| {
+    ///     let attribute = if (i % 10 + i / 10) % 2 == 0 { 0xF0 } else { 0x0F };
+    ///     CharInfo::new(' ', attribute)
+    /// });
+    ///
+    /// WinConsole::output().write_cells(cells, SmallRect::new(0, 0, 9, 4)).unwrap();
+    /// ```
+    ///
+    /// [`write_output`]: #method.write_output
+    pub fn write_cells<I>(&self, cells: I, region: SmallRect) -> Result<()> where I: IntoIterator<Item = CharInfo> {
+        let width = region.width().max(0) as usize;
+        let height = region.height().max(0) as usize;
+        let area = width * height;
+        // Takes one more cell to detect if there are too many
+        let buffer = cells.into_iter().take(area + 1).collect::<Vec<CharInfo>>();
+
+        if buffer.len() != area {
+            let got = if buffer.len() > area { "more".to_string() } else { buffer.len().to_string() };
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("expected {} cells for a {}x{} region but got {}", area, width, height, got)
+            ));
+        }
+
+        self.write_output(&buffer, Coord::new(width as i16, height as i16), Coord::ZERO, region)
+    }
+
+    /// Writes the given cells in a single row of the screen buffer starting at the specified position,
+    /// without moving the cursor.
+    ///
+    /// Unlike saving the cursor position, writing and restoring it, this is done with a single
+    /// call to `WriteConsoleOutputW` which writes to an explicit region, so other writers can't
+    /// see or move the cursor in the middle of the operation, useful for overlays and status bars.
+    ///
+    /// Wraps a call to [WriteConsoleOutputW](https://docs.microsoft.com/en-us/windows/console/writeconsoleoutput).
+    ///
+    /// # Remarks
+    /// - The characters are written as they are, the control characters as `\n` or `\t`
+    ///   are not interpreted as when using [`write_utf8`].
+    /// - The cells that exceed the width of the screen buffer are discarded, they don't wrap to the next row.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - `ErrorKind::InvalidInput` if there are more cells than the maximum width of a screen buffer.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::char_info::CharInfo;
+    /// use win32console::structs::coord::Coord;
+    ///
+    /// let status = "Ready".chars().map(|c| CharInfo::new(c, 0x70)).collect::<Vec<_>>();
+    /// let cursor = WinConsole::output().get_cursor_position().unwrap();
+    ///
+    /// WinConsole::output().write_at_atomic(Coord::new(0, 0), &status).unwrap();
+    /// assert_eq!(cursor, WinConsole::output().get_cursor_position().unwrap());
+    /// ```
+    ///
+    /// [`write_utf8`]: #method.write_utf8
+    pub fn write_at_atomic(&self, pos: Coord, cells: &[CharInfo]) -> Result<()> {
+        if cells.is_empty() {
+            return Ok(());
+        }
+
+        if cells.len() > i16::MAX as usize {
+            return Err(Error::new(ErrorKind::InvalidInput, "too many cells for a single row"));
+        }
+
+        let width = cells.len() as i16;
+        let region = SmallRect::new(pos.x, pos.y, pos.x.saturating_add(width - 1), pos.y);
+        self.write_output(cells, Coord::new(width, 1), Coord::ZERO, region)
+    }
+
+    /// Writes the given text horizontally centered in the console window, in the specified row
+    /// of the window and using the given attribute, without moving the cursor.
+    ///
+    /// The `row` is relative to the top of the window, and the text is centered in the visible columns
+    /// even if the window is scrolled. If the text is wider than the window, is written starting
+    /// at the left edge of the window.
+    ///
+    /// # Remarks
+    /// - The width of the text is measured in `char`s, so wide characters may not be centered exactly.
+    /// - The text is written using [`write_at_atomic`] so the control characters are not interpreted.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::{WinConsole, ConsoleTextAttribute};
+    ///
+    /// WinConsole::output().clear().unwrap();
+    /// WinConsole::output().write_centered("Main Menu", 1, ConsoleTextAttribute::FOREGROUND_INTENSITY).unwrap();
+    /// ```
+    ///
+    /// [`write_at_atomic`]: #method.write_at_atomic
+    pub fn write_centered(&self, text: &str, row: i16, attribute: u16) -> Result<()> {
+        let window = self.get_window_rect()?;
+        let width = window.width().max(0) as usize;
+        let cells = text.chars()
+            .map(|c| CharInfo::new(c, attribute))
+            .collect::<Vec<_>>();
+
+        let column = width.saturating_sub(cells.len()) / 2;
+        let pos = window.top_left().saturating_add(Coord::new(column as i16, row));
+        self.write_at_atomic(pos, &cells)
+    }
+
+    /// Writes data directly to the console input buffer.
+    ///
+    /// Wraps a call to [WriteConsoleInputA](https://docs.microsoft.com/en-us/windows/console/writeconsoleinput).
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::input_record::InputRecord;
+    /// use win32console::structs::input_event::{KeyEventRecord, ControlKeyState};
+    /// use win32console::structs::input_record::InputRecord::KeyEvent;
+    /// use win32console::console::WinConsole;
+    /// use winapi::_core::mem::MaybeUninit;
+    ///
+    /// let mut key_event : KeyEventRecord = unsafe { std::mem::zeroed() };
+    /// key_event.repeat_count = 1;
+    /// key_event.control_key_state = ControlKeyState::new(0);
+    /// key_event.u_char = 'a';
+    /// key_event.key_down = true;
+    /// key_event.virtual_scan_code = 0;
+    /// key_event.virtual_key_code = 0x41;
+    ///
+    /// // Discard all the records in the buffer
+    /// WinConsole::input().flush_input();
+    ///
+    /// let record : [InputRecord; 1] = [KeyEvent(key_event)];
+    /// WinConsole::input().write_input(&record).expect("Cannot write the event");
+    ///
+    /// let mut buf : [InputRecord; 1] = unsafe { MaybeUninit::zeroed().assume_init() };
+    /// WinConsole::input().peek_input(&mut buf).expect("Cannot peek the events");
+    ///
+    /// assert_eq!(record, buf);
+    /// ```
+    pub fn write_input(&self, buffer: &[InputRecord]) -> Result<usize>{
+        if buffer.len() == 0{
+            return Ok(0);
+        }
+
+        let mut buf = buffer.iter()
+            .map(|c| (*c).into())
+            .collect::<Vec<INPUT_RECORD>>();
+
+        let handle = self.get_handle();
+        let mut events_written = 0;
+
+        unsafe{
+            if WriteConsoleInputA(**handle, buf.as_mut_ptr(), buf.len() as u32, &mut events_written) == 0{
+                Err(Error::last_os_error())
+            }
+            else{
+                Ok(events_written as usize)
+            }
+        }
+    }
+
+    /// Writes the given raw `INPUT_RECORD`s directly to the console input buffer,
+    /// without converting them from [`InputRecord`], useful to inject a large number of input events
+    /// when the raw records are already available.
+    ///
+    /// Wraps a call to [WriteConsoleInputW](https://docs.microsoft.com/en-us/windows/console/writeconsoleinput).
+    ///
+    /// # Remarks
+    /// The records are passed as they are to the console, so each record must be well formed:
+    /// - The `EventType` must be one of `KEY_EVENT`, `MOUSE_EVENT`, `WINDOW_BUFFER_SIZE_EVENT`,
+    ///   `FOCUS_EVENT` or `MENU_EVENT`, and the `Event` union must be initialized with the matching record.
+    /// - The characters of the key events are read as `UTF-16` code units from `uChar.UnicodeChar`.
+    ///
+    /// Malformed records are not rejected and may be read back as invalid events.
+    ///
+    /// # Returns
+    /// The number of input events written.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    ///   the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::input_event::KeyEventRecord;
+    /// use win32console::structs::input_record::InputRecord;
+    /// use winapi::um::wincon::INPUT_RECORD;
+    ///
+    /// let mut key_event : KeyEventRecord = unsafe { std::mem::zeroed() };
+    /// key_event.repeat_count = 1;
+    /// key_event.key_down = true;
+    /// key_event.u_char = 'a';
+    /// key_event.virtual_key_code = 0x41;
+    ///
+    /// let raw : INPUT_RECORD = InputRecord::KeyEvent(key_event).into();
+    /// let records = vec![raw; 1000];
+    ///
+    /// WinConsole::input().flush_input().unwrap();
+    /// assert_eq!(1000, WinConsole::input().write_raw_input(&records).unwrap());
+    /// WinConsole::input().flush_input().unwrap();
+    /// ```
+    ///
+    /// [`InputRecord`]: ../structs/input_record/enum.InputRecord.html
+    pub fn write_raw_input(&self, records: &[INPUT_RECORD]) -> Result<usize>{
+        if records.is_empty(){
+            return Ok(0);
+        }
+
+        let handle = self.get_handle();
+        let mut events_written = 0;
+
+        unsafe{
+            if WriteConsoleInputW(**handle, records.as_ptr(), records.len() as u32, &mut events_written) == 0{
+                Err(Error::last_os_error())
+            }
+            else{
+                Ok(events_written as usize)
+            }
+        }
+    }
+
+    /// Copies a number of character attributes to consecutive cells of a console screen buffer, beginning at a specified location.
+    ///
+    /// Wraps a call to [WriteConsoleOutputAttribute](https://docs.microsoft.com/en-us/windows/console/writeconsoleoutputattribute).
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Examples
+    /// ```
+    /// use win32console::console::{WinConsole, ConsoleTextAttribute};
+    /// use win32console::structs::coord::Coord;
+    ///
+    /// WinConsole::output().clear();
+    /// WinConsole::output().write_utf8(b"RGB");
+    ///
+    /// let attributes : [u16; 3] = [ConsoleTextAttribute::FOREGROUND_RED, ConsoleTextAttribute::FOREGROUND_GREEN, ConsoleTextAttribute::FOREGROUND_BLUE];
+    /// WinConsole::output().write_output_attribute(&attributes, Coord::ZERO);
+    /// ```
+    pub fn write_output_attribute(&self, attributes: &[u16], write_coord: Coord) -> Result<usize>{
+        if attributes.len() == 0{
+            return Ok(0);
+        }
+
+        let handle = self.get_handle();
+
+        unsafe{
+            let mut written_attributes = 0;
+            if WriteConsoleOutputAttribute(**handle, attributes.as_ptr(), attributes.len() as u32, write_coord.into(), &mut written_attributes) == 0{
+                Err(Error::last_os_error())
+            }
+            else{
+                Ok(written_attributes as usize)
+            }
+        }
+    }
+
+    /// Copies a number of characters to consecutive cells of a console screen buffer, beginning at a specified location.
+    ///
+    /// Wraps a call to [WriteConsoleOutputCharacterW](https://docs.microsoft.com/en-us/windows/console/writeconsoleoutputcharacter).
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    /// the function should be called using `WinConsole::input()` or a valid input handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::coord::Coord;
+    ///
+    /// WinConsole::output().clear();
+    /// WinConsole::output().write_utf8("*".repeat(15).as_bytes());
+    /// WinConsole::output().write_output_character(b"Hello", Coord::new(5, 0));
+    /// ```
+    pub fn write_output_character(&self, buffer: &[u8], write_coord: Coord) -> Result<usize>{
+        if buffer.len() == 0{
+            return Ok(0);
+        }
+
+        let handle = self.get_handle();
+        let mut chars_written = 0;
+        let utf16_buffer = match str::from_utf8(buffer){
+            Ok(string) => {
+                string.encode_utf16().collect::<Vec<u16>>()
+            },
+            Err(e) => {
+                return Err(Error::new(ErrorKind::InvalidData, e));
+            },
+        };
+
+        unsafe{
+            if WriteConsoleOutputCharacterW(**handle, utf16_buffer.as_ptr(), utf16_buffer.len() as u32, write_coord.into(), &mut chars_written) == 0{
+                Err(Error::last_os_error())
+            }
+            else{
+                Ok(chars_written as usize)
+            }
+        }
+    }
+
+    /// Checks if the handle is a handle to a console
+    #[inline]
+    fn is_console(handle: &Handle) -> bool {
+        let mut mode = 0;
+        unsafe { GetConsoleMode(**handle, &mut mode) != 0 }
+    }
+
+    /// Checks if the handle is a console input handle.
+    fn is_input_console(handle: &Handle) -> bool {
+        let mut num_events = 0;
+        unsafe { GetNumberOfConsoleInputEvents(**handle, &mut num_events) != 0 }
+    }
+
+    /// Checks if the handle is a console screen buffer handle.
+    fn is_output_console(handle: &Handle) -> bool {
+        let mut info: CONSOLE_SCREEN_BUFFER_INFO = unsafe { std::mem::zeroed() };
+        unsafe { GetConsoleScreenBufferInfo(**handle, &mut info) != 0 }
+    }
+
+    /// Multiplies the number of cells by the size of each cell.
+    #[inline]
+    fn scale_cells(cells: Coord, cell_size: Coord) -> (i32, i32) {
+        (cells.x as i32 * cell_size.x as i32, cells.y as i32 * cell_size.y as i32)
+    }
+
+    /// Joins the consecutive segments that have the same attribute.
+    fn group_styled_segments(segments: &[(&str, u16)]) -> Vec<(String, u16)> {
+        let mut groups: Vec<(String, u16)> = Vec::new();
+
+        for (text, attribute) in segments {
+            match groups.last_mut() {
+                Some((group_text, group_attribute)) if group_attribute == attribute => {
+                    group_text.push_str(text);
+                }
+                _ => groups.push((text.to_string(), *attribute)),
+            }
+        }
+
+        groups
+    }
+
+    /// Removes the CSI escape sequences (`ESC [ parameters intermediates final`) of the given text,
+    /// incomplete sequences and any other escape sequence are left untouched.
+    fn strip_ansi_sequences(text: &str) -> String {
+        let bytes = text.as_bytes();
+        let mut result = String::with_capacity(text.len());
+        let mut start = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            if bytes[i] == 0x1B && bytes.get(i + 1) == Some(&b'[') {
+                // Parameter bytes (0x30-0x3F), intermediate bytes (0x20-0x2F) and the final byte (0x40-0x7E)
+                let mut end = i + 2;
+                while end < bytes.len() && (0x30..=0x3F).contains(&bytes[end]) {
+                    end += 1;
+                }
+                while end < bytes.len() && (0x20..=0x2F).contains(&bytes[end]) {
+                    end += 1;
+                }
+
+                if end < bytes.len() && (0x40..=0x7E).contains(&bytes[end]) {
+                    result.push_str(&text[start..i]);
+                    i = end + 1;
+                    start = i;
+                    continue;
+                }
+            }
+
+            i += 1;
+        }
+
+        result.push_str(&text[start..]);
+        result
+    }
+
+    /// Converts the content of the given utf16 buffer to utf8 and writes it to the
+    /// destination buffer, the conversion stops when the next character don't fit in the destination.
+    fn utf16_to_utf8(source: &[u16], destination: &mut [u8]) -> Result<usize> {
+        // The actual number of utf8 characters written to the destination buffer
+        let mut written = 0;
+
+        let utf16_iterator = source.iter().cloned();
+        for chr in std::char::decode_utf16(utf16_iterator) {
+            match chr {
+                Ok(value) => {
+                    if written + value.len_utf8() > destination.len() {
+                        break;
+                    }
+
+                    value.encode_utf8(&mut destination[written..]);
+                    written += value.len_utf8();
+                }
+                Err(e) => {
+                    return Err(Error::new(ErrorKind::InvalidData, e));
+                }
+            }
+        }
+
+        Ok(written)
+    }
+}
+
+// Checked methods
+impl WinConsole {
+    /// Gets the current screen buffer info, the same as [`get_screen_buffer_info`] but returning a [`ConsoleError`].
+    ///
+    /// # Errors
+    /// - `ConsoleError::InvalidHandle` if the handle is an invalid handle or an input handle.
+    /// - `ConsoleError::NotAConsole` if the handle is not a console handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// let info = WinConsole::output().get_screen_buffer_info_checked().unwrap();
+    /// ```
+    ///
+    /// [`get_screen_buffer_info`]: #method.get_screen_buffer_info
+    /// [`ConsoleError`]: ../error/enum.ConsoleError.html
+    pub fn get_screen_buffer_info_checked(&self) -> std::result::Result<ConsoleScreenBufferInfo, ConsoleError> {
+        self.check_console()?;
+        self.get_screen_buffer_info().map_err(ConsoleError::from)
+    }
+
+    /// Set the size of the console screen buffer, the same as [`set_screen_buffer_size`] but returning a [`ConsoleError`].
+    ///
+    /// # Errors
+    /// - `ConsoleError::InvalidHandle` if the handle is an invalid handle or an input handle.
+    /// - `ConsoleError::NotAConsole` if the handle is not a console handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::coord::Coord;
+    /// WinConsole::output().set_screen_buffer_size_checked(Coord::new(80, 100)).unwrap();
+    /// ```
+    ///
+    /// [`set_screen_buffer_size`]: #method.set_screen_buffer_size
+    /// [`ConsoleError`]: ../error/enum.ConsoleError.html
+    pub fn set_screen_buffer_size_checked(&self, size: Coord) -> std::result::Result<(), ConsoleError> {
+        self.check_console()?;
+        self.set_screen_buffer_size(size).map_err(ConsoleError::from)
+    }
+
+    /// Sets the current size and position of the console screen buffer window,
+    /// the same as [`set_window_info`] but returning a [`ConsoleError`].
+    ///
+    /// # Errors
+    /// - `ConsoleError::InvalidHandle` if the handle is an invalid handle or an input handle.
+    /// - `ConsoleError::NotAConsole` if the handle is not a console handle,
+    ///   as when running in the console of some IDEs.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::error::ConsoleError;
+    /// use win32console::structs::small_rect::SmallRect;
+    ///
+    /// let window = SmallRect::new(0, 0, 40, 20);
+    /// match WinConsole::output().set_window_info_checked(true, &window){
+    ///     Err(ConsoleError::NotAConsole) => { println!("Not running in a console") }
+    ///     result => result.unwrap()
+    /// }
+    /// ```
+    ///
+    /// [`set_window_info`]: #method.set_window_info
+    /// [`ConsoleError`]: ../error/enum.ConsoleError.html
+    pub fn set_window_info_checked(&self, absolute: bool, window: &SmallRect) -> std::result::Result<(), ConsoleError> {
+        self.check_console()?;
+        self.set_window_info(absolute, window).map_err(ConsoleError::from)
+    }
+
+    /// Sets the position of the cursor, the same as [`set_cursor_position`] but returning a [`ConsoleError`].
+    ///
+    /// # Errors
+    /// - `ConsoleError::InvalidHandle` if the handle is an invalid handle or an input handle.
+    /// - `ConsoleError::NotAConsole` if the handle is not a console handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::coord::Coord;
+    /// WinConsole::output().set_cursor_position_checked(Coord::new(5, 5)).unwrap();
+    /// ```
+    ///
+    /// [`set_cursor_position`]: #method.set_cursor_position
+    /// [`ConsoleError`]: ../error/enum.ConsoleError.html
+    pub fn set_cursor_position_checked(&self, coord: Coord) -> std::result::Result<(), ConsoleError> {
+        self.check_console()?;
+        self.set_cursor_position(coord).map_err(ConsoleError::from)
+    }
+
+    /// Sets the current mode of the console, the same as [`set_mode`] but returning a [`ConsoleError`].
+    ///
+    /// # Errors
+    /// - `ConsoleError::InvalidHandle` if the handle is an invalid handle.
+    /// - `ConsoleError::NotAConsole` if the handle is not a console handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// let mode = WinConsole::input().get_mode().unwrap();
+    /// WinConsole::input().set_mode_checked(mode).unwrap();
+    /// ```
+    ///
+    /// [`set_mode`]: #method.set_mode
+    /// [`ConsoleError`]: ../error/enum.ConsoleError.html
+    pub fn set_mode_checked(&self, mode: u32) -> std::result::Result<(), ConsoleError> {
+        self.check_console()?;
+        self.set_mode(mode).map_err(ConsoleError::from)
+    }
+
+    /// Checks the handle is valid and is a console handle.
+    fn check_console(&self) -> std::result::Result<(), ConsoleError> {
+        let handle = self.get_handle();
+
+        if !handle.is_valid() || handle.is_null() {
+            return Err(ConsoleError::InvalidHandle);
+        }
+
+        if !WinConsole::is_console(handle) {
+            return Err(ConsoleError::NotAConsole);
+        }
+
+        Ok(())
+    }
+}
+
+// ConsoleColor methods
+impl WinConsole {
+    const FG_COLOR_MARK: u16 = 0xF;
+    const BG_COLOR_MASK: u16 = 0xF0;
+
+    /// Gets the foreground color of the console.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::console_color::ConsoleColor;
+    /// let fg = WinConsole::output().get_foreground_color().unwrap();
+    /// let bg = WinConsole::output().get_background_color().unwrap();
+    ///
+    /// WinConsole::output().set_foreground_color(ConsoleColor::Red);
+    /// WinConsole::output().set_background_color(ConsoleColor::Yellow);
+    /// WinConsole::output().write_utf8("Hello World!".as_bytes());
+    ///
+    /// // Restore colors
+    /// WinConsole::output().set_foreground_color(fg);
+    /// WinConsole::output().set_background_color(bg);
+    /// ```
+    #[inline]
+    pub fn get_foreground_color(&self) -> Result<ConsoleColor> {
+        let attributes = self.get_text_attribute()?;
+        ConsoleColor::from_foreground_attribute(attributes).map_err(Error::from)
+    }
+
+    /// Gets the foreground color of the console, the same as [`get_foreground_color`] but returning
+    /// a [`ConsoleError`] which also reports when the color of the text attribute is not a valid [`ConsoleColor`].
+    ///
+    /// # Errors
+    /// - `ConsoleError::InvalidHandle` if the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - `ConsoleError::InvalidColor` if the color is not a valid `ConsoleColor`.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::error::ConsoleError;
+    ///
+    /// match WinConsole::output().try_get_foreground_color(){
+    ///     Ok(color) => println!("Foreground: {}", color),
+    ///     Err(ConsoleError::InvalidColor(e)) => println!("Unknown foreground color: {}", e),
+    ///     Err(e) => println!("Cannot read the foreground color: {}", e)
+    /// }
+    /// ```
+    ///
+    /// [`get_foreground_color`]: #method.get_foreground_color
+    /// [`ConsoleError`]: ../error/enum.ConsoleError.html
+    /// [`ConsoleColor`]: ../structs/console_color/enum.ConsoleColor.html
+    pub fn try_get_foreground_color(&self) -> std::result::Result<ConsoleColor, ConsoleError> {
+        let attributes = self.get_text_attribute()?;
+        Ok(ConsoleColor::from_foreground_attribute(attributes)?)
+    }
+
+    /// Gets the background color of the console.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::console_color::ConsoleColor;
+    /// let fg = WinConsole::output().get_foreground_color().unwrap();
+    /// let bg = WinConsole::output().get_background_color().unwrap();
+    ///
+    /// WinConsole::output().set_foreground_color(ConsoleColor::Black);
+    /// WinConsole::output().set_background_color(ConsoleColor::White);
+    /// WinConsole::output().write_utf8("Hello World!".as_bytes());
+    ///
+    /// // Restore colors
+    /// WinConsole::output().set_foreground_color(fg);
+    /// WinConsole::output().set_background_color(bg);
+    /// ```
+    #[inline]
+    pub fn get_background_color(&self) -> Result<ConsoleColor> {
+        let attributes = self.get_text_attribute()?;
+        ConsoleColor::from_background_attribute(attributes).map_err(Error::from)
+    }
+
+    /// Gets the background color of the console, the same as [`get_background_color`] but returning
+    /// a [`ConsoleError`] which also reports when the color of the text attribute is not a valid [`ConsoleColor`].
+    ///
+    /// # Errors
+    /// - `ConsoleError::InvalidHandle` if the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    /// - `ConsoleError::InvalidColor` if the color is not a valid `ConsoleColor`.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::error::ConsoleError;
+    ///
+    /// match WinConsole::output().try_get_background_color(){
+    ///     Ok(color) => println!("Background: {}", color),
+    ///     Err(ConsoleError::InvalidColor(e)) => println!("Unknown background color: {}", e),
+    ///     Err(e) => println!("Cannot read the background color: {}", e)
+    /// }
+    /// ```
+    ///
+    /// [`get_background_color`]: #method.get_background_color
+    /// [`ConsoleError`]: ../error/enum.ConsoleError.html
+    /// [`ConsoleColor`]: ../structs/console_color/enum.ConsoleColor.html
+    pub fn try_get_background_color(&self) -> std::result::Result<ConsoleColor, ConsoleError> {
+        let attributes = self.get_text_attribute()?;
+        Ok(ConsoleColor::from_background_attribute(attributes)?)
+    }
+
+    /// Sets the foreground color of the console.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::console_color::ConsoleColor;
+    /// let fg = WinConsole::output().get_foreground_color().unwrap();
+    /// let bg = WinConsole::output().get_background_color().unwrap();
+    ///
+    /// WinConsole::output().set_foreground_color(ConsoleColor::Yellow);
+    /// WinConsole::output().set_background_color(ConsoleColor::DarkMagenta);
+    /// WinConsole::output().write_utf8("Hello World!".as_bytes());
+    ///
+    /// // Restore colors
+    /// WinConsole::output().set_foreground_color(fg);
+    /// WinConsole::output().set_background_color(bg);
+    /// ```
+    pub fn set_foreground_color(&self, color: ConsoleColor) -> Result<()> {
+        let old_attributes = self.get_text_attribute()?;
+        let new_attributes = (old_attributes
+            & !(old_attributes & WinConsole::FG_COLOR_MARK)) | color.as_foreground_color();
+        self.set_text_attribute(new_attributes)
+    }
+
+    /// Sets the background color of the console.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    /// the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::console_color::ConsoleColor;
+    /// let fg = WinConsole::output().get_foreground_color().unwrap();
+    /// let bg = WinConsole::output().get_background_color().unwrap();
+    ///
+    /// WinConsole::output().set_foreground_color(ConsoleColor::DarkBlue);
+    /// WinConsole::output().set_background_color(ConsoleColor::Green);
+    /// WinConsole::output().write_utf8("Hello World!".as_bytes());
+    ///
+    /// // Restore colors
+    /// WinConsole::output().set_foreground_color(fg);
     /// WinConsole::output().set_background_color(bg);
     /// ```
     pub fn set_background_color(&self, color: ConsoleColor) -> Result<()> {
@@ -3030,6 +6631,161 @@ impl WinConsole {
             & !(old_attributes & WinConsole::BG_COLOR_MASK)) | color.as_background_color();
         self.set_text_attribute(new_attributes)
     }
+
+    /// Gets the foreground and background colors of the console with a single read of the text attribute.
+    ///
+    /// # Returns
+    /// A tuple with the `(foreground, background)` colors.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::console_color::ConsoleColor;
+    ///
+    /// let (fg, bg) = WinConsole::output().get_colors().unwrap();
+    ///
+    /// WinConsole::output().set_foreground_color(ConsoleColor::Red).unwrap();
+    /// WinConsole::output().write_utf8("Hello World!".as_bytes()).unwrap();
+    ///
+    /// // Restore colors
+    /// WinConsole::output().set_colors(fg, bg).unwrap();
+    /// ```
+    pub fn get_colors(&self) -> Result<(ConsoleColor, ConsoleColor)> {
+        let attributes = self.get_text_attribute()?;
+        let foreground = ConsoleColor::from_foreground_attribute(attributes).map_err(Error::from)?;
+        let background = ConsoleColor::from_background_attribute(attributes).map_err(Error::from)?;
+        Ok((foreground, background))
+    }
+
+    /// Sets the foreground and background colors of the console with a single call to
+    /// `SetConsoleTextAttribute`, the other bits of the text attribute are preserved.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::console_color::ConsoleColor;
+    ///
+    /// let (fg, bg) = WinConsole::output().get_colors().unwrap();
+    ///
+    /// WinConsole::output().set_colors(ConsoleColor::White, ConsoleColor::DarkRed).unwrap();
+    /// WinConsole::output().write_utf8("Error!".as_bytes()).unwrap();
+    ///
+    /// WinConsole::output().set_colors(fg, bg).unwrap();
+    /// ```
+    pub fn set_colors(&self, foreground: ConsoleColor, background: ConsoleColor) -> Result<()> {
+        let old_attributes = self.get_text_attribute()?;
+        let new_attributes = (old_attributes & !(WinConsole::FG_COLOR_MARK | WinConsole::BG_COLOR_MASK))
+            | foreground.as_foreground_color()
+            | background.as_background_color();
+        self.set_text_attribute(new_attributes)
+    }
+
+    /// Writes the given text using the specified foreground color, and restores the color afterward.
+    ///
+    /// If the `ENABLE_VIRTUAL_TERMINAL_PROCESSING` mode is enabled the color and the previous foreground color
+    /// are set with virtual terminal sequences, otherwise the text attribute is set before writing
+    /// and the previous text attribute is restored after.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::console_color::ConsoleColor;
+    ///
+    /// WinConsole::output().print_color("Warning: ", ConsoleColor::Yellow).unwrap();
+    /// WinConsole::output().write_utf8(b"the file already exists").unwrap();
+    /// ```
+    pub fn print_color(&self, text: &str, color: ConsoleColor) -> Result<()> {
+        let mode = self.get_mode()?;
+        let old_attributes = self.get_text_attribute()?;
+
+        if mode & ConsoleMode::ENABLE_VIRTUAL_TERMINAL_PROCESSING != 0 {
+            let old_color = ConsoleColor::from_foreground_attribute(old_attributes)?;
+            let sequence = format!("\x1b[{}m{}\x1b[{}m", color.as_ansi_foreground(), text, old_color.as_ansi_foreground());
+            return self.write_all_utf8(sequence.as_bytes());
+        }
+
+        let result = self.set_text_attribute((old_attributes & !WinConsole::FG_COLOR_MARK) | color.as_foreground_color())
+            .and_then(|_| self.write_all_utf8(text.as_bytes()));
+
+        self.set_text_attribute(old_attributes)?;
+        result
+    }
+
+    /// Prints a grid with every combination of foreground and background colors, each row uses a
+    /// background color and each column a foreground color, and each cell shows its attribute in hexadecimal.
+    ///
+    /// This is useful to check how the color palette of the console looks.
+    /// The original text attribute is restored after the grid is printed.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// let attribute = WinConsole::output().get_text_attribute().unwrap();
+    /// WinConsole::output().print_color_test().unwrap();
+    /// assert_eq!(attribute, WinConsole::output().get_text_attribute().unwrap());
+    /// ```
+    pub fn print_color_test(&self) -> Result<()> {
+        let old_attributes = self.get_text_attribute()?;
+
+        let result = (|| {
+            for background in 0..16_u16 {
+                for foreground in 0..16_u16 {
+                    let attribute = (old_attributes & !(WinConsole::FG_COLOR_MARK | WinConsole::BG_COLOR_MASK))
+                        | (background << 4)
+                        | foreground;
+
+                    self.set_text_attribute(attribute)?;
+                    self.write_all_utf8(format!(" {:X}{:X} ", background, foreground).as_bytes())?;
+                }
+
+                self.set_text_attribute(old_attributes)?;
+                self.write_all_utf8(b"\n")?;
+            }
+
+            Ok(())
+        })();
+
+        self.set_text_attribute(old_attributes)?;
+        result
+    }
+
+    /// Swaps the foreground and background colors of the console,
+    /// useful for highlight text as a selected item of a menu.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an input handle: `WinConsole::input()`,
+    ///   the function should be called using `WinConsole::output()` or a valid output handle.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// WinConsole::output().invert_colors().unwrap();
+    /// WinConsole::output().write_utf8(b"> Selected item\n").unwrap();
+    /// WinConsole::output().invert_colors().unwrap();
+    /// WinConsole::output().write_utf8(b"  Other item\n").unwrap();
+    /// ```
+    pub fn invert_colors(&self) -> Result<()> {
+        let attributes = self.get_text_attribute()?;
+        self.set_text_attribute(ConsoleColor::swap_fg_bg(attributes))
+    }
 }
 
 // No console methods
@@ -3065,3 +6821,940 @@ impl WinConsole{
         }
     }
 }
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::test_utils::{lock_console, key_events, RedirectedFile};
+
+    #[test]
+    fn scale_cells_test(){
+        assert_eq!((640, 400), WinConsole::scale_cells(Coord::new(80, 25), Coord::new(8, 16)));
+        assert_eq!((1_073_676_289, 0), WinConsole::scale_cells(Coord::new(i16::MAX, 0), Coord::new(i16::MAX, 16)));
+    }
+
+    #[test]
+    fn group_styled_segments_test(){
+        let groups = WinConsole::group_styled_segments(&[("Hello", 4), (" ", 4), ("World", 2), ("!", 4)]);
+        assert_eq!(vec![
+            ("Hello ".to_string(), 4),
+            ("World".to_string(), 2),
+            ("!".to_string(), 4)
+        ], groups);
+
+        assert!(WinConsole::group_styled_segments(&[]).is_empty());
+    }
+
+    #[test]
+    fn strip_ansi_sequences_test(){
+        assert_eq!("Red", WinConsole::strip_ansi_sequences("\x1b[31mRed\x1b[0m"));
+        assert_eq!("Bold Text", WinConsole::strip_ansi_sequences("\x1b[1;4mBold\x1b[0m Text"));
+        assert_eq!("ABC", WinConsole::strip_ansi_sequences("A\x1b[2KB\x1b[10;20HC"));
+        assert_eq!("Hello World!", WinConsole::strip_ansi_sequences("Hello World!"));
+    }
+
+    #[test]
+    fn strip_ansi_sequences_untouched_test(){
+        // Incomplete sequence
+        assert_eq!("Red\x1b[31", WinConsole::strip_ansi_sequences("Red\x1b[31"));
+        // Not a CSI sequence
+        assert_eq!("\x1b]0;Title\x07", WinConsole::strip_ansi_sequences("\x1b]0;Title\x07"));
+        // Non-ascii characters
+        assert_eq!("ñandú", WinConsole::strip_ansi_sequences("\x1b[32mñandú\x1b[m"));
+    }
+
+    #[test]
+    fn read_available_input_test(){
+        let _lock = lock_console();
+        let records = key_events("abc");
+
+        let input = WinConsole::input();
+        input.flush_input().unwrap();
+        assert_eq!(3, input.write_input(&records).unwrap());
+        assert_eq!(records, input.read_available_input().unwrap());
+    }
+
+    #[test]
+    fn with_output_code_page_restore_test(){
+        let _lock = lock_console();
+        let old_code_page = WinConsole::get_output_code_page().unwrap();
+        let code_page = WinConsole::with_output_code_page(437, || WinConsole::get_output_code_page().unwrap()).unwrap();
+
+        assert_eq!(437, code_page);
+        assert_eq!(old_code_page, WinConsole::get_output_code_page().unwrap());
+    }
+
+    #[test]
+    fn with_output_code_page_restore_on_panic_test(){
+        let _lock = lock_console();
+        let old_code_page = WinConsole::get_output_code_page().unwrap();
+        let result = std::panic::catch_unwind(|| {
+            WinConsole::with_output_code_page(437, || panic!("Expected panic")).unwrap();
+        });
+
+        assert!(result.is_err());
+        assert_eq!(old_code_page, WinConsole::get_output_code_page().unwrap());
+    }
+
+    #[test]
+    fn get_original_title_without_nul_test(){
+        // The original title may not be available if there is no console
+        if let Ok(title) = WinConsole::get_original_title(){
+            assert!(!title.contains('\0'));
+        }
+    }
+
+    #[test]
+    fn draw_horizontal_line_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        let attribute = ConsoleTextAttribute::FOREGROUND_GREEN | ConsoleTextAttribute::BACKGROUND_BLUE;
+        console.clear().unwrap();
+        console.draw_horizontal_line(Coord::new(2, 1), 4, '=', attribute).unwrap();
+
+        let mut chars = [0_u8; 6];
+        let mut attributes = [0_u16; 4];
+        console.read_output_character(&mut chars, Coord::new(1, 1)).unwrap();
+        console.read_output_attribute(&mut attributes, Coord::new(2, 1)).unwrap();
+
+        assert_eq!(b" ==== ", &chars);
+        assert_eq!([attribute; 4], attributes);
+    }
+
+    #[test]
+    fn draw_vertical_line_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        let attribute = ConsoleTextAttribute::FOREGROUND_RED;
+        console.clear().unwrap();
+        console.draw_vertical_line(Coord::new(3, 1), 3, '|', attribute).unwrap();
+
+        for y in 0..5{
+            let mut chars = [0_u8; 1];
+            let mut attributes = [0_u16; 1];
+            console.read_output_character(&mut chars, Coord::new(3, y)).unwrap();
+            console.read_output_attribute(&mut attributes, Coord::new(3, y)).unwrap();
+
+            if (1..4).contains(&y){
+                assert_eq!(b"|", &chars);
+                assert_eq!(attribute, attributes[0]);
+            } else {
+                assert_eq!(b" ", &chars);
+            }
+        }
+    }
+
+    #[test]
+    fn draw_vertical_line_past_bottom_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        console.clear().unwrap();
+
+        let last_row = console.get_screen_buffer_size().unwrap().y - 1;
+        console.draw_vertical_line(Coord::new(0, last_row), u32::MAX, '|', ConsoleTextAttribute::FOREGROUND_RED).unwrap();
+
+        let mut chars = [0_u8; 1];
+        console.read_output_character(&mut chars, Coord::new(0, last_row)).unwrap();
+        assert_eq!(b"|", &chars);
+    }
+
+    // Ignored because the result depends on whether the user is selecting text in the console window.
+    #[test]
+    #[ignore]
+    fn get_selection_rect_without_selection_test(){
+        assert_eq!(None, WinConsole::get_selection_rect().unwrap());
+        assert_eq!(None, WinConsole::get_selection_anchor().unwrap());
+    }
+
+    #[test]
+    fn set_input_mode_test(){
+        let _lock = lock_console();
+        let input = WinConsole::input();
+        let mode = input.get_mode().unwrap();
+        input.set_input_mode(mode).unwrap();
+    }
+
+    #[test]
+    fn set_output_mode_test(){
+        let _lock = lock_console();
+        let output = WinConsole::output();
+        let mode = output.get_mode().unwrap();
+        output.set_output_mode(mode).unwrap();
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "console output handle")]
+    fn set_input_mode_with_output_handle_test(){
+        let _ = WinConsole::output().set_input_mode(ConsoleMode::ENABLE_PROCESSED_INPUT);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "console input handle")]
+    fn set_output_mode_with_input_handle_test(){
+        let _ = WinConsole::input().set_output_mode(ConsoleMode::ENABLE_PROCESSED_OUTPUT);
+    }
+
+    #[test]
+    fn printable_chars_test(){
+        let _lock = lock_console();
+        let mut records = key_events("a\rb\x1b\tc");
+        if let InputRecord::KeyEvent(key_event) = &mut records[2] {
+            key_event.key_down = false;
+        }
+
+        let input = WinConsole::input();
+        input.flush_input().unwrap();
+        input.write_input(&records).unwrap();
+
+        assert_eq!("ac", input.printable_chars().take(2).collect::<String>());
+    }
+
+    #[test]
+    fn set_underscore_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        let old_mode = console.get_mode().unwrap();
+        let old_attribute = console.get_text_attribute().unwrap();
+
+        console.set_underscore(true).unwrap();
+        assert_ne!(0, console.get_text_attribute().unwrap() & ConsoleTextAttribute::COMMON_LVB_UNDERSCORE);
+
+        console.set_underscore(false).unwrap();
+        assert_eq!(0, console.get_text_attribute().unwrap() & ConsoleTextAttribute::COMMON_LVB_UNDERSCORE);
+        console.set_text_attribute(old_attribute).unwrap();
+        console.set_mode(old_mode).unwrap();
+    }
+
+    #[test]
+    fn set_reverse_video_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        let old_mode = console.get_mode().unwrap();
+        let old_attribute = console.get_text_attribute().unwrap();
+
+        console.set_reverse_video(true).unwrap();
+        assert_ne!(0, console.get_text_attribute().unwrap() & ConsoleTextAttribute::COMMON_LVB_REVERSE_VIDEO);
+
+        console.set_reverse_video(false).unwrap();
+        assert_eq!(0, console.get_text_attribute().unwrap() & ConsoleTextAttribute::COMMON_LVB_REVERSE_VIDEO);
+        console.set_text_attribute(old_attribute).unwrap();
+        console.set_mode(old_mode).unwrap();
+    }
+
+    #[test]
+    fn write_utf8_non_ascii_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        console.clear().unwrap();
+
+        let data = "café".as_bytes();
+        assert_eq!(data.len(), console.write_utf8(data).unwrap());
+
+        let mut buf = vec![0_u8; data.len()];
+        console.read_output_character(&mut buf, Coord::ZERO).unwrap();
+        assert_eq!(data, buf.as_slice());
+    }
+
+    #[test]
+    fn has_pending_input_after_flush_test(){
+        let _lock = lock_console();
+        let input = WinConsole::input();
+        input.flush_input().unwrap();
+        assert!(!input.has_pending_input().unwrap());
+    }
+
+    #[test]
+    fn write_cells_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        let region = SmallRect::new(1, 1, 2, 2);
+        let cells = vec![
+            CharInfo::new('a', 0x1F), CharInfo::new('b', 0x2F),
+            CharInfo::new('c', 0x3F), CharInfo::new('d', 0x4F)
+        ];
+
+        console.write_cells(cells.iter().copied(), region).unwrap();
+
+        let mut read_region = region;
+        let read = console.read_output(Coord::new(2, 2), Coord::ZERO, &mut read_region).unwrap();
+        assert_eq!(cells, read);
+    }
+
+    #[test]
+    fn write_cells_count_mismatch_test(){
+        let cells = (0..3).map(|_| CharInfo::new('x', 0x7));
+        let error = WinConsole::output().write_cells(cells, SmallRect::new(0, 0, 1, 1)).unwrap_err();
+        assert_eq!(ErrorKind::InvalidInput, error.kind());
+    }
+
+    #[test]
+    fn write_cells_infinite_test(){
+        let cells = std::iter::repeat(CharInfo::new(' ', 0x7));
+        let error = WinConsole::output().write_cells(cells, SmallRect::new(0, 0, 1, 1)).unwrap_err();
+        assert_eq!(ErrorKind::InvalidInput, error.kind());
+        assert!(error.to_string().ends_with("but got more"));
+    }
+
+    #[test]
+    fn get_colors_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        let old_attribute = console.get_text_attribute().unwrap();
+
+        console.set_text_attribute(ConsoleColor::Yellow.as_foreground_color() | ConsoleColor::DarkBlue.as_background_color()).unwrap();
+        assert_eq!((ConsoleColor::Yellow, ConsoleColor::DarkBlue), console.get_colors().unwrap());
+        console.set_text_attribute(old_attribute).unwrap();
+    }
+
+    #[test]
+    fn set_colors_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        let old_attribute = console.get_text_attribute().unwrap();
+
+        console.set_text_attribute(ConsoleTextAttribute::COMMON_LVB_UNDERSCORE).unwrap();
+        console.set_colors(ConsoleColor::Green, ConsoleColor::DarkMagenta).unwrap();
+
+        assert_eq!(ConsoleColor::Green, console.get_foreground_color().unwrap());
+        assert_eq!(ConsoleColor::DarkMagenta, console.get_background_color().unwrap());
+        assert_ne!(0, console.get_text_attribute().unwrap() & ConsoleTextAttribute::COMMON_LVB_UNDERSCORE);
+        console.set_text_attribute(old_attribute).unwrap();
+    }
+
+    #[test]
+    fn scoped_raw_mode_test(){
+        let _lock = lock_console();
+        let input = WinConsole::input();
+        let old_mode = input.get_mode().unwrap();
+        let raw_flags = ConsoleMode::ENABLE_PROCESSED_INPUT | ConsoleMode::ENABLE_LINE_INPUT | ConsoleMode::ENABLE_ECHO_INPUT;
+
+        {
+            let guard = input.scoped_raw_mode().unwrap();
+            assert_eq!(old_mode, guard.get_old_mode());
+            assert_eq!(0, input.get_mode().unwrap() & raw_flags);
+        }
+
+        assert_eq!(old_mode, input.get_mode().unwrap());
+    }
+
+    #[test]
+    fn read_input_up_to_test(){
+        let _lock = lock_console();
+        let records = key_events("xxxxx");
+        let input = WinConsole::input();
+        input.flush_input().unwrap();
+        input.write_input(&records).unwrap();
+
+        // Reads the first 3 events at once
+        let read = input.read_input_up_to(3, Duration::from_millis(50)).unwrap();
+        assert_eq!(&records[..3], read.as_slice());
+
+        // Reads the remaining events and returns after the quiet period
+        let read = input.read_input_up_to(10, Duration::from_millis(50)).unwrap();
+        assert_eq!(&records[3..], read.as_slice());
+
+        let read = input.read_input_up_to(10, Duration::from_millis(10)).unwrap();
+        assert!(read.is_empty());
+    }
+
+    #[test]
+    fn try_get_colors_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        let old_attribute = console.get_text_attribute().unwrap();
+
+        console.set_colors(ConsoleColor::Cyan, ConsoleColor::DarkRed).unwrap();
+        assert_eq!(ConsoleColor::Cyan, console.try_get_foreground_color().unwrap());
+        assert_eq!(ConsoleColor::DarkRed, console.try_get_background_color().unwrap());
+        console.set_text_attribute(old_attribute).unwrap();
+    }
+
+    #[test]
+    fn try_get_colors_invalid_handle_test(){
+        let console = WinConsole::with_handle(Handle::new(INVALID_HANDLE_VALUE));
+        assert!(matches!(console.try_get_foreground_color(), Err(ConsoleError::InvalidHandle)));
+        assert!(matches!(console.try_get_background_color(), Err(ConsoleError::InvalidHandle)));
+    }
+
+    #[test]
+    fn write_lines_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        console.clear().unwrap();
+        console.write_lines(&["one", "two", "three"]).unwrap();
+
+        assert!(console.snapshot_text().unwrap().starts_with("one\ntwo\nthree\n"));
+        assert_eq!(Coord::new(0, 3), console.get_cursor_position().unwrap());
+    }
+
+    #[test]
+    fn get_font_ex_test(){
+        let info = WinConsole::output().get_font_ex(false).unwrap();
+        assert_eq!(std::mem::size_of::<CONSOLE_FONT_INFOEX>() as u32, info.size);
+    }
+
+    #[test]
+    fn with_alternate_screen_restore_test(){
+        let _lock = lock_console();
+
+        // `CONOUT$` is always the active screen buffer
+        let active = || WinConsole::with_handle(WinConsole::get_current_output_handle().unwrap());
+        let read_marker = || {
+            let mut buf = [0_u8; 4];
+            active().read_output_character(&mut buf, Coord::ZERO).unwrap();
+            buf
+        };
+
+        active().write_output_character(b"main", Coord::ZERO).unwrap();
+
+        WinConsole::with_alternate_screen(|screen| {
+            screen.write_output_character(b"alt1", Coord::ZERO).unwrap();
+            assert_eq!(b"alt1", &read_marker());
+
+            WinConsole::with_alternate_screen(|nested| {
+                nested.write_output_character(b"alt2", Coord::ZERO).unwrap();
+                assert_eq!(b"alt2", &read_marker());
+            }).unwrap();
+
+            assert_eq!(b"alt1", &read_marker());
+        }).unwrap();
+
+        assert_eq!(b"main", &read_marker());
+    }
+
+    #[test]
+    fn save_screen_surrogate_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        console.clear().unwrap();
+
+        let cell = CharInfo::from_raw(0xD83D, 0x07);
+        console.write_output(&[cell], Coord::new(1, 1), Coord::ZERO, SmallRect::new(0, 0, 0, 0)).unwrap();
+
+        let saved = console.save_screen().unwrap();
+        assert_eq!(0xD83D, saved.get_buffer()[0].raw_char());
+
+        console.clear().unwrap();
+        console.restore_screen(&saved).unwrap();
+        assert_eq!(saved, console.save_screen().unwrap());
+    }
+
+    #[test]
+    fn reset_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        let old_attribute = console.get_text_attribute().unwrap();
+
+        console.clear().unwrap();
+        console.write_utf8(b"text").unwrap();
+        console.set_text_attribute(0x1F).unwrap();
+
+        // Keeps the content and the cursor
+        console.reset(false).unwrap();
+        assert_eq!(0x7, console.get_text_attribute().unwrap());
+        assert!(console.get_cursor_info().unwrap().visible);
+        assert_eq!(Coord::new(4, 0), console.get_cursor_position().unwrap());
+
+        let mut buf = [0_u8; 4];
+        console.read_output_character(&mut buf, Coord::ZERO).unwrap();
+        assert_eq!(b"text", &buf);
+
+        // Clears the content
+        console.set_text_attribute(0x1F).unwrap();
+        console.reset(true).unwrap();
+        assert_eq!(0x7, console.get_text_attribute().unwrap());
+        assert_eq!(Coord::ZERO, console.get_cursor_position().unwrap());
+
+        console.read_output_character(&mut buf, Coord::ZERO).unwrap();
+        assert_eq!(b"    ", &buf);
+
+        console.set_text_attribute(old_attribute).unwrap();
+    }
+
+    #[test]
+    fn write_at_atomic_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        console.clear().unwrap();
+        console.write_utf8(b"prompt> ").unwrap();
+        let cursor = console.get_cursor_position().unwrap();
+
+        let cells = "status".chars().map(|c| CharInfo::new(c, 0x70)).collect::<Vec<_>>();
+        console.write_at_atomic(Coord::new(10, 2), &cells).unwrap();
+        assert_eq!(cursor, console.get_cursor_position().unwrap());
+
+        let mut buf = [0_u8; 6];
+        console.read_output_character(&mut buf, Coord::new(10, 2)).unwrap();
+        assert_eq!(b"status", &buf);
+    }
+
+    #[test]
+    fn write_utf8_tracked_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        console.clear().unwrap();
+        console.set_cursor_position(Coord::new(3, 2)).unwrap();
+
+        let end = console.write_utf8_tracked(b"Hello").unwrap();
+        assert_eq!(Coord::new(8, 2), end);
+        assert_eq!(end, console.get_cursor_position().unwrap());
+    }
+
+    #[test]
+    fn capabilities_restores_modes_test(){
+        let _lock = lock_console();
+        let input_mode = WinConsole::input().get_mode().unwrap();
+        let output_mode = WinConsole::output().get_mode().unwrap();
+
+        let capabilities = WinConsole::capabilities();
+        assert!(capabilities.virtual_terminal || !capabilities.truecolor);
+
+        assert_eq!(input_mode, WinConsole::input().get_mode().unwrap());
+        assert_eq!(output_mode, WinConsole::output().get_mode().unwrap());
+    }
+
+    #[test]
+    fn read_until_test(){
+        let _lock = lock_console();
+        let records = key_events("abcd");
+
+        let input = WinConsole::input();
+        input.flush_input().unwrap();
+        input.write_input(&records).unwrap();
+
+        let record = input.read_until(|r| matches!(r.as_key_event(), Some(e) if e.u_char == 'c')).unwrap();
+        assert_eq!(records[2], record);
+        assert_eq!(vec![records[3]], input.read_available_input().unwrap());
+    }
+
+    #[test]
+    fn take_text_attribute_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        let old_attribute = console.get_text_attribute().unwrap();
+        let new_attribute = ConsoleTextAttribute::FOREGROUND_RED | ConsoleTextAttribute::BACKGROUND_BLUE;
+
+        assert_eq!(old_attribute, console.take_text_attribute(new_attribute).unwrap());
+        assert_eq!(new_attribute, console.get_text_attribute().unwrap());
+
+        assert_eq!(new_attribute, console.take_text_attribute(old_attribute).unwrap());
+        assert_eq!(old_attribute, console.get_text_attribute().unwrap());
+    }
+
+    #[test]
+    fn write_utf8_redirected_invalid_test(){
+        let file = RedirectedFile::create("win32console_write_utf8_invalid.txt");
+
+        let error = file.console().write_utf8(b"abc\xFFdef").unwrap_err();
+        assert_eq!(ErrorKind::InvalidInput, error.kind());
+        assert!(error.to_string().contains("byte offset 3"));
+        assert!(file.contents().is_empty());
+    }
+
+    #[test]
+    fn write_utf8_redirected_incomplete_char_test(){
+        let file = RedirectedFile::create("win32console_write_utf8_incomplete.txt");
+        let console = file.console();
+        let data = "añ".as_bytes();
+
+        // Only the complete characters are written
+        assert_eq!(1, console.write_utf8(&data[..2]).unwrap());
+        assert_eq!(2, console.write_utf8(&data[1..]).unwrap());
+        assert_eq!(data, file.contents().as_slice());
+
+        let error = console.write_utf8(&data[1..2]).unwrap_err();
+        assert_eq!(ErrorKind::InvalidInput, error.kind());
+    }
+
+    #[test]
+    fn attach_parent_console_already_attached_test(){
+        // The test process is already attached to a console
+        let error = WinConsole::attach_parent_console().unwrap_err();
+        assert_eq!(ErrorKind::AlreadyExists, error.kind());
+    }
+
+    #[test]
+    fn write_raw_input_test(){
+        let _lock = lock_console();
+        let records = key_events("xyz");
+        let raw = records.iter()
+            .map(|r| (*r).into())
+            .collect::<Vec<INPUT_RECORD>>();
+
+        let input = WinConsole::input();
+        input.flush_input().unwrap();
+        assert_eq!(3, input.write_raw_input(&raw).unwrap());
+        assert_eq!(records, input.read_available_input().unwrap());
+    }
+
+    #[test]
+    fn vk_to_char_test(){
+        const VK_A: u16 = 0x41;
+        const VK_F1: u16 = 0x70;
+        const VK_LEFT: u16 = 0x25;
+
+        assert_eq!(Some('a'), WinConsole::vk_to_char(VK_A, &ControlKeyState::none()));
+        assert_eq!(Some('A'), WinConsole::vk_to_char(VK_A, &ControlKeyState::none().with_shift()));
+        assert_eq!(None, WinConsole::vk_to_char(VK_F1, &ControlKeyState::none()));
+        assert_eq!(None, WinConsole::vk_to_char(VK_LEFT, &ControlKeyState::none()));
+    }
+
+    #[test]
+    fn request_shutdown_zero_group_test(){
+        let error = WinConsole::request_shutdown(0).unwrap_err();
+        assert_eq!(ErrorKind::InvalidInput, error.kind());
+    }
+
+    #[test]
+    fn read_line_edited_test(){
+        let _lock = lock_console();
+        let records = key_events("hellx\x08o\r");
+
+        let input = WinConsole::input();
+        input.flush_input().unwrap();
+        input.write_input(&records).unwrap();
+
+        assert_eq!("hello", input.read_line_edited().unwrap());
+    }
+
+    #[test]
+    fn screen_buffer_info_for_test(){
+        let handle = WinConsole::create_console_screen_buffer().unwrap();
+        let info = WinConsole::screen_buffer_info_for(&handle).unwrap();
+
+        assert_eq!(Coord::ZERO, info.cursor_position);
+        assert!(info.screen_buffer_size.x > 0 && info.screen_buffer_size.y > 0);
+        assert_eq!(info, WinConsole::with_handle(handle).get_screen_buffer_info().unwrap());
+    }
+
+    #[test]
+    fn write_output_redirected_test(){
+        let file = RedirectedFile::create("win32console_write_output_redirected.txt");
+
+        let cells = [CharInfo::new('a', 0x07); 4];
+        let error = file.console().write_output(&cells, Coord::new(4, 1), Coord::ZERO, SmallRect::new(0, 0, 3, 0)).unwrap_err();
+        assert_eq!(ErrorKind::Unsupported, error.kind());
+        assert!(error.to_string().contains("redirected"));
+    }
+
+    #[test]
+    fn write_output_invalid_handle_test(){
+        let console = WinConsole::with_handle(Handle::new(INVALID_HANDLE_VALUE));
+
+        let cells = [CharInfo::new('a', 0x07); 4];
+        let error = console.write_output(&cells, Coord::new(4, 1), Coord::ZERO, SmallRect::new(0, 0, 3, 0)).unwrap_err();
+        assert_eq!(Some(ERROR_INVALID_HANDLE as i32), error.raw_os_error());
+    }
+
+    #[test]
+    fn wrap_text_test(){
+        let lines = WinConsole::wrap_text("Lorem ipsum dolor sit amet,   consectetur adipiscing", 11);
+        assert_eq!(vec!["Lorem ipsum", "dolor sit", "amet,", "consectetur", "adipiscing"], lines);
+    }
+
+    #[test]
+    fn wrap_text_long_word_test(){
+        let lines = WinConsole::wrap_text("a abcdefghijkl b", 5);
+        assert_eq!(vec!["a", "abcde", "fghij", "kl b"], lines);
+    }
+
+    #[test]
+    fn wrap_text_newlines_test(){
+        let lines = WinConsole::wrap_text("one two three\r\n\nfour", 7);
+        assert_eq!(vec!["one two", "three", "", "four"], lines);
+        assert!(WinConsole::wrap_text("", 10).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn wrap_text_zero_width_test(){
+        WinConsole::wrap_text("text", 0);
+    }
+
+    #[test]
+    fn read_with_prefix_redirected_test(){
+        let file = RedirectedFile::with_contents("win32console_read_with_prefix.txt", b"world\r\nnext line\r\n");
+
+        let console = file.console();
+        assert_eq!("hello world", console.read_with_prefix("hello ").unwrap());
+        assert_eq!("next line", console.read_with_prefix("").unwrap());
+    }
+
+    #[test]
+    fn read_with_prefix_console_test(){
+        let _lock = lock_console();
+        let output = WinConsole::with_handle(WinConsole::get_current_output_handle().unwrap());
+        output.clear().unwrap();
+
+        let input = WinConsole::input();
+        input.flush_input().unwrap();
+        input.write_input(&key_events("world\r")).unwrap();
+        assert_eq!("hello world", input.read_with_prefix("hello ").unwrap());
+
+        // The prefix is echoed to the active screen buffer
+        let mut buf = [0_u8; 11];
+        output.read_output_character(&mut buf, Coord::ZERO).unwrap();
+        assert_eq!(b"hello world", &buf);
+    }
+
+    #[test]
+    fn write_ansi_without_virtual_terminal_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        let old_mode = console.get_mode().unwrap();
+        console.set_mode(old_mode & !ConsoleMode::ENABLE_VIRTUAL_TERMINAL_PROCESSING).unwrap();
+
+        console.clear().unwrap();
+        assert_eq!(3, console.write_ansi("\x1b[31mRed\x1b[0m").unwrap());
+
+        let mut buf = [0_u8; 4];
+        console.read_output_character(&mut buf, Coord::ZERO).unwrap();
+        assert_eq!(b"Red ", &buf);
+
+        console.set_mode(old_mode).unwrap();
+    }
+
+    #[test]
+    fn write_ansi_virtual_terminal_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        if !console.supports_virtual_terminal() {
+            return;
+        }
+
+        let old_mode = console.get_mode().unwrap();
+        let old_attribute = console.get_text_attribute().unwrap();
+        console.set_mode(old_mode | ConsoleMode::ENABLE_VIRTUAL_TERMINAL_PROCESSING).unwrap();
+
+        console.clear().unwrap();
+        let text = "\x1b[31mRed\x1b[0m";
+        assert_eq!(text.len(), console.write_ansi(text).unwrap());
+
+        let mut buf = [0_u8; 4];
+        console.read_output_character(&mut buf, Coord::ZERO).unwrap();
+        assert_eq!(b"Red ", &buf);
+
+        console.set_text_attribute(old_attribute).unwrap();
+        console.set_mode(old_mode).unwrap();
+    }
+
+    #[test]
+    fn print_color_classic_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        let old_mode = console.get_mode().unwrap();
+        let old_attribute = console.get_text_attribute().unwrap();
+        console.set_mode(old_mode & !ConsoleMode::ENABLE_VIRTUAL_TERMINAL_PROCESSING).unwrap();
+
+        console.clear().unwrap();
+        console.print_color("X", ConsoleColor::Red).unwrap();
+
+        let mut attribute = [0_u16; 1];
+        console.read_output_attribute(&mut attribute, Coord::ZERO).unwrap();
+        assert_eq!(ConsoleColor::Red, ConsoleColor::from_foreground_attribute(attribute[0]).unwrap());
+        assert_eq!(old_attribute, console.get_text_attribute().unwrap());
+
+        console.set_mode(old_mode).unwrap();
+    }
+
+    #[test]
+    fn print_color_virtual_terminal_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        if !console.supports_virtual_terminal() {
+            return;
+        }
+
+        let old_mode = console.get_mode().unwrap();
+        let old_attribute = console.get_text_attribute().unwrap();
+        console.set_mode(old_mode | ConsoleMode::ENABLE_VIRTUAL_TERMINAL_PROCESSING).unwrap();
+
+        console.clear().unwrap();
+        console.print_color("X", ConsoleColor::Red).unwrap();
+
+        let mut attribute = [0_u16; 1];
+        console.read_output_attribute(&mut attribute, Coord::ZERO).unwrap();
+        assert_eq!(ConsoleColor::Red, ConsoleColor::from_foreground_attribute(attribute[0]).unwrap());
+        assert_eq!(old_attribute, console.get_text_attribute().unwrap());
+
+        console.set_mode(old_mode).unwrap();
+    }
+
+    #[test]
+    fn input_buffer_remaining_test(){
+        let _lock = lock_console();
+        let input = WinConsole::input();
+        input.flush_input().unwrap();
+        assert_eq!(WinConsole::INPUT_BUFFER_CAPACITY, input.input_buffer_remaining().unwrap());
+        assert!(!input.is_input_buffer_near_full().unwrap());
+
+        let records = key_events(&"a".repeat(240));
+        input.write_input(&records).unwrap();
+        assert_eq!(16, input.input_buffer_remaining().unwrap());
+        assert!(input.is_input_buffer_near_full().unwrap());
+
+        input.flush_input().unwrap();
+        assert_eq!(WinConsole::INPUT_BUFFER_CAPACITY, input.input_buffer_remaining().unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn load_and_present_round_trip_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        console.clear().unwrap();
+
+        let cells = "splash".chars().map(|c| CharInfo::new(c, 0x1E)).collect::<Vec<_>>();
+        console.write_at_atomic(Coord::new(2, 1), &cells).unwrap();
+        let saved = console.save_screen().unwrap();
+
+        let path = std::env::temp_dir().join("win32console_load_and_present.json");
+        serde_json::to_writer(std::fs::File::create(&path).unwrap(), &saved).unwrap();
+
+        console.clear().unwrap();
+        console.load_and_present(&path).unwrap();
+        assert_eq!(saved, console.save_screen().unwrap());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn set_wrap_at_eol_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        let old_mode = console.get_mode().unwrap();
+        console.clear().unwrap();
+
+        let last_column = console.get_screen_buffer_info().unwrap().screen_buffer_size.x - 1;
+        console.set_wrap_at_eol(false).unwrap();
+        assert_eq!(0, console.get_mode().unwrap() & ConsoleMode::ENABLE_WRAP_AT_EOL_OUTPUT);
+
+        console.set_cursor_position(Coord::new(last_column - 2, 0)).unwrap();
+        console.write_utf8(b"abcdef").unwrap();
+        assert_eq!(0, console.get_cursor_position().unwrap().y);
+
+        let mut buf = [0_u8; 3];
+        console.read_output_character(&mut buf, Coord::new(last_column - 2, 0)).unwrap();
+        assert_eq!(b"abf", &buf);
+
+        let mut next_row = [0_u8; 1];
+        console.read_output_character(&mut next_row, Coord::new(0, 1)).unwrap();
+        assert_eq!(b" ", &next_row);
+
+        console.set_mode(old_mode).unwrap();
+    }
+
+    #[test]
+    fn set_disable_newline_auto_return_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        let old_mode = console.get_mode().unwrap();
+
+        console.set_disable_newline_auto_return(true).unwrap();
+        assert_ne!(0, console.get_mode().unwrap() & ConsoleMode::DISABLE_NEWLINE_AUTO_RETURN);
+
+        console.set_disable_newline_auto_return(false).unwrap();
+        assert_eq!(0, console.get_mode().unwrap() & ConsoleMode::DISABLE_NEWLINE_AUTO_RETURN);
+
+        console.set_mode(old_mode).unwrap();
+    }
+
+    #[test]
+    fn describe_handle_std_test(){
+        let input = WinConsole::input().describe_handle();
+        assert_eq!(HandleInfo { is_valid: true, is_console: true, is_input: true, is_output: false }, input);
+
+        let output = WinConsole::output().describe_handle();
+        assert_eq!(HandleInfo { is_valid: true, is_console: true, is_input: false, is_output: true }, output);
+    }
+
+    #[test]
+    fn describe_handle_invalid_test(){
+        let info = WinConsole::with_handle(Handle::new(INVALID_HANDLE_VALUE)).describe_handle();
+        assert!(!info.is_valid && !info.is_console && !info.is_input && !info.is_output);
+    }
+
+    #[test]
+    fn rows_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        console.clear().unwrap();
+        console.write_utf8(b"first row  \r\n  second row").unwrap();
+
+        let rows = console.rows().unwrap();
+        let window = console.get_window_rect().unwrap();
+
+        assert_eq!(window.height() as usize, rows.len());
+        assert_eq!("first row", rows[0]);
+        assert_eq!("  second row", rows[1]);
+        assert!(rows[2..].iter().all(|row| row.is_empty()));
+    }
+
+    #[test]
+    fn flush_output_redirected_test(){
+        let file = RedirectedFile::create("win32console_flush_output.txt");
+
+        let console = file.console();
+        console.write_utf8(b"flushed data").unwrap();
+        console.flush_output().unwrap();
+        assert_eq!(b"flushed data", file.contents().as_slice());
+    }
+
+    #[test]
+    fn flush_output_console_test(){
+        WinConsole::output().flush_output().unwrap();
+    }
+
+    #[test]
+    fn write_centered_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        console.clear().unwrap();
+
+        let width = console.get_window_size().unwrap().x as usize;
+        console.write_centered("Title", 2, ConsoleTextAttribute::FOREGROUND_GREEN).unwrap();
+
+        let rows = console.rows().unwrap();
+        let column = (width - 5) / 2;
+        assert_eq!(format!("{}Title", " ".repeat(column)), rows[2]);
+    }
+
+    #[test]
+    fn write_centered_scrolled_window_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        console.clear().unwrap();
+
+        let window = console.get_window_rect().unwrap();
+        if console.get_screen_buffer_size().unwrap().y < window.height() * 2 {
+            return;
+        }
+
+        // Scrolls the window one page down, the row is relative to the window
+        let scrolled = SmallRect::new(window.left, window.top + window.height(), window.right, window.bottom + window.height());
+        console.set_window_info(true, &scrolled).unwrap();
+        console.write_centered("Title", 2, ConsoleTextAttribute::FOREGROUND_GREEN).unwrap();
+
+        let rows = console.rows().unwrap();
+        let column = (window.width() as usize - 5) / 2;
+        assert_eq!(format!("{}Title", " ".repeat(column)), rows[2]);
+
+        console.set_window_info(true, &window).unwrap();
+    }
+
+    #[test]
+    fn write_centered_long_text_test(){
+        let _lock = lock_console();
+        let console = WinConsole::output();
+        console.clear().unwrap();
+
+        let width = console.get_window_size().unwrap().x as usize;
+        let text = "x".repeat(width + 10);
+        console.write_centered(&text, 0, ConsoleTextAttribute::FOREGROUND_GREEN).unwrap();
+
+        let rows = console.rows().unwrap();
+        assert_eq!("x".repeat(width), rows[0]);
+    }
+}
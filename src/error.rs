@@ -0,0 +1,141 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{self, ErrorKind};
+use winapi::shared::winerror::{ERROR_ACCESS_DENIED, ERROR_INSUFFICIENT_BUFFER, ERROR_INVALID_HANDLE};
+use crate::structs::console_color::ParseColorError;
+
+/// Represents an error of a console operation.
+///
+/// The common errors returned by the console functions are mapped to its own variant so can be
+/// easily matched, any other error is wrapped in `ConsoleError::Os`.
+///
+/// # Example
+/// ```
+/// use win32console::console::WinConsole;
+/// use win32console::error::ConsoleError;
+/// use win32console::structs::small_rect::SmallRect;
+///
+/// let window = SmallRect::new(0, 0, 40, 20);
+/// match WinConsole::output().set_window_info_checked(true, &window){
+///     Ok(()) => {},
+///     Err(ConsoleError::NotAConsole) => { println!("The output is being redirected") },
+///     Err(e) => { println!("Cannot resize the window: {}", e) }
+/// }
+/// ```
+#[derive(Debug)]
+pub enum ConsoleError {
+    /// The handle is not valid for the operation, `ERROR_INVALID_HANDLE`.
+    InvalidHandle,
+    /// The handle is not a console handle, as when the input or output is being redirected.
+    NotAConsole,
+    /// The access to the console was denied, `ERROR_ACCESS_DENIED`.
+    AccessDenied,
+    /// The buffer provided is too small for the operation, `ERROR_INSUFFICIENT_BUFFER`.
+    BufferTooSmall,
+    /// The color of a text attribute is not a valid `ConsoleColor`.
+    InvalidColor(ParseColorError),
+    /// Any other error returned by the operating system.
+    Os(io::Error),
+}
+
+impl ConsoleError {
+    /// Gets a `ConsoleError` from the last error of the operating system.
+    #[inline]
+    pub fn last_os_error() -> ConsoleError {
+        ConsoleError::from(io::Error::last_os_error())
+    }
+}
+
+impl Display for ConsoleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConsoleError::InvalidHandle => f.write_str("The handle is invalid"),
+            ConsoleError::NotAConsole => f.write_str("The handle is not a console handle"),
+            ConsoleError::AccessDenied => f.write_str("Access to the console is denied"),
+            ConsoleError::BufferTooSmall => f.write_str("The buffer is too small"),
+            ConsoleError::InvalidColor(e) => Display::fmt(e, f),
+            ConsoleError::Os(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+impl Error for ConsoleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConsoleError::InvalidColor(e) => Some(e),
+            ConsoleError::Os(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ConsoleError {
+    fn from(error: io::Error) -> Self {
+        match error.raw_os_error().map(|code| code as u32) {
+            Some(ERROR_INVALID_HANDLE) => ConsoleError::InvalidHandle,
+            Some(ERROR_ACCESS_DENIED) => ConsoleError::AccessDenied,
+            Some(ERROR_INSUFFICIENT_BUFFER) => ConsoleError::BufferTooSmall,
+            _ => ConsoleError::Os(error),
+        }
+    }
+}
+
+impl From<ParseColorError> for ConsoleError {
+    #[inline]
+    fn from(error: ParseColorError) -> Self {
+        ConsoleError::InvalidColor(error)
+    }
+}
+
+impl From<ConsoleError> for io::Error {
+    fn from(error: ConsoleError) -> Self {
+        match error {
+            ConsoleError::InvalidHandle => io::Error::new(ErrorKind::InvalidInput, error),
+            ConsoleError::NotAConsole => io::Error::new(ErrorKind::InvalidInput, error),
+            ConsoleError::AccessDenied => io::Error::new(ErrorKind::PermissionDenied, error),
+            ConsoleError::BufferTooSmall => io::Error::new(ErrorKind::InvalidInput, error),
+            ConsoleError::InvalidColor(_) => io::Error::new(ErrorKind::InvalidData, error),
+            ConsoleError::Os(e) => e,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn from_io_error_test(){
+        let error = ConsoleError::from(io::Error::from_raw_os_error(ERROR_ACCESS_DENIED as i32));
+        assert!(matches!(error, ConsoleError::AccessDenied));
+
+        let error = ConsoleError::from(io::Error::from_raw_os_error(ERROR_INVALID_HANDLE as i32));
+        assert!(matches!(error, ConsoleError::InvalidHandle));
+
+        let error = ConsoleError::from(io::Error::from_raw_os_error(ERROR_INSUFFICIENT_BUFFER as i32));
+        assert!(matches!(error, ConsoleError::BufferTooSmall));
+
+        let error = ConsoleError::from(io::Error::from_raw_os_error(87));
+        assert!(matches!(error, ConsoleError::Os(_)));
+    }
+
+    #[test]
+    fn into_io_error_test(){
+        assert_eq!(ErrorKind::PermissionDenied, io::Error::from(ConsoleError::AccessDenied).kind());
+        assert_eq!(ErrorKind::InvalidInput, io::Error::from(ConsoleError::NotAConsole).kind());
+
+        let os_error = io::Error::from(ConsoleError::Os(io::Error::from_raw_os_error(87)));
+        assert_eq!(Some(87), os_error.raw_os_error());
+    }
+
+    #[test]
+    fn from_parse_color_error_test(){
+        use crate::structs::console_color::ConsoleColor;
+        use std::convert::TryFrom;
+
+        let error = ConsoleError::from(ConsoleColor::try_from(16).unwrap_err());
+        assert!(matches!(error, ConsoleError::InvalidColor(_)));
+        assert_eq!("Invalid color value: 16", error.to_string());
+        assert_eq!(ErrorKind::InvalidData, io::Error::from(error).kind());
+    }
+}
@@ -34,9 +34,7 @@
 //!                 let char_value = key.u_char;
 //!                 // Write only if is alphanumeric or punctuation
 //!                 if char_value.is_ascii_alphanumeric() || char_value.is_ascii_punctuation(){
-//!                     let mut value : [u8; 1] = [0];
-//!                     char_value.encode_utf8(&mut value);
-//!                     WinConsole::output().write_utf8(&value);
+//!                     WinConsole::output().write_char(char_value);
 //!                 }
 //!                 else{
 //!                     match key.virtual_key_code {
@@ -57,4 +55,12 @@
 pub mod console;
 /// Includes console related structs as `ConsoleColor`, `CharInfo` or `ConsoleCursorInfo`.
 pub mod structs;
+/// Provides the `ConsoleError` for a typed representation of the console errors.
+pub mod error;
+/// Provides the `BufferedConsole` for write to the console with an internal buffer.
+pub mod buffered;
+/// Provides the `InputReader` for read input events into a reusable buffer.
+pub mod reader;
 pub mod input;
+#[cfg(test)]
+mod test_utils;
@@ -0,0 +1,146 @@
+use std::fmt::{Debug, Formatter};
+use std::io::Result;
+use winapi::um::wincon::INPUT_RECORD;
+use crate::console::WinConsole;
+use crate::structs::input_record::InputRecord;
+
+/// Wraps a [`WinConsole`] and reads the input events into an internal buffer which is reused
+/// on each read, so reading the input in a loop don't allocate a new buffer each time.
+///
+/// # Example
+/// ```
+/// use win32console::console::WinConsole;
+/// use win32console::reader::InputReader;
+/// use win32console::input::*;
+///
+/// let mut reader = InputReader::new(WinConsole::input());
+/// loop {
+///     // Blocks until at least one input event is available
+///     let records = reader.read().unwrap();
+///     let escape = records.iter().any(|record| match record {
+///         KeyEvent(key) => key.key_down && key.virtual_key_code == 0x1B,
+///         _ => false
+///     });
+///
+///     if escape {
+///         break;
+///     }
+/// }
+/// ```
+///
+/// [`WinConsole`]: ../console/struct.WinConsole.html
+pub struct InputReader{
+    // The console where the input events are read.
+    console: WinConsole,
+    // The input events read in the last call to `read`.
+    buffer: Vec<InputRecord>,
+    // The raw input events passed to the console, reused to avoid an allocation on each read.
+    raw_buffer: Vec<INPUT_RECORD>
+}
+
+impl InputReader{
+    /// The default number of input events read at once.
+    pub const DEFAULT_CAPACITY: usize = 128;
+
+    /// Creates a new `InputReader` with the default capacity.
+    #[inline]
+    pub fn new(console: WinConsole) -> Self{
+        InputReader::with_capacity(InputReader::DEFAULT_CAPACITY, console)
+    }
+
+    /// Creates a new `InputReader` that reads up to the specified number of input events at once.
+    ///
+    /// # Panics
+    /// - If `capacity` is 0.
+    pub fn with_capacity(capacity: usize, console: WinConsole) -> Self{
+        assert!(capacity > 0, "the capacity of the InputReader must be greater than 0");
+
+        InputReader{
+            console,
+            buffer: vec![unsafe { std::mem::zeroed::<InputRecord>() }; capacity],
+            raw_buffer: vec![unsafe { std::mem::zeroed::<INPUT_RECORD>() }; capacity]
+        }
+    }
+
+    /// Gets the console where the input events are read.
+    #[inline]
+    pub fn get_console(&self) -> &WinConsole{
+        &self.console
+    }
+
+    /// Gets the maximum number of input events read at once.
+    #[inline]
+    pub fn get_capacity(&self) -> usize{
+        self.buffer.len()
+    }
+
+    /// Reads the available input events into the internal buffer, blocking until at least
+    /// one input event is available.
+    ///
+    /// See [`WinConsole::read_input`].
+    ///
+    /// # Returns
+    /// A slice of the input events read, which is valid until the next call to `read`.
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle or an output handle: `WinConsole::output()`,
+    ///   the reader should be created using `WinConsole::input()` or a valid input handle.
+    ///
+    /// [`WinConsole::read_input`]: ../console/struct.WinConsole.html#method.read_input
+    pub fn read(&mut self) -> Result<&[InputRecord]>{
+        let count = self.console.read_input_raw(&mut self.raw_buffer)?;
+
+        for (record, raw) in self.buffer.iter_mut().zip(&self.raw_buffer[..count]) {
+            *record = (*raw).into();
+        }
+
+        Ok(&self.buffer[..count])
+    }
+}
+
+impl Debug for InputReader{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // `INPUT_RECORD` don't implement `Debug`, the raw buffer is a copy of `buffer`
+        f.debug_struct("InputReader")
+            .field("console", &self.console)
+            .field("buffer", &self.buffer)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::test_utils::{lock_console, key_events};
+
+    #[test]
+    fn read_reuses_buffer_test(){
+        let _lock = lock_console();
+        let input = WinConsole::input();
+        input.flush_input().unwrap();
+
+        let mut reader = InputReader::with_capacity(8, WinConsole::input());
+        let buffer_ptr = reader.buffer.as_ptr();
+        let raw_buffer_ptr = reader.raw_buffer.as_ptr();
+
+        let first = key_events("abc");
+        input.write_input(&first).unwrap();
+        assert_eq!(first.as_slice(), reader.read().unwrap());
+
+        let second = key_events("de");
+        input.write_input(&second).unwrap();
+        assert_eq!(second.as_slice(), reader.read().unwrap());
+
+        assert_eq!(8, reader.get_capacity());
+        assert_eq!(8, reader.buffer.capacity());
+        assert_eq!(buffer_ptr, reader.buffer.as_ptr());
+        assert_eq!(8, reader.raw_buffer.capacity());
+        assert_eq!(raw_buffer_ptr, reader.raw_buffer.as_ptr());
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_zero_capacity_test(){
+        InputReader::with_capacity(0, WinConsole::input());
+    }
+}
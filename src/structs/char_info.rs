@@ -1,16 +1,21 @@
 use winapi::um::wincon::{ CHAR_INFO };
+use std::char::REPLACEMENT_CHARACTER;
 use std::convert::TryFrom;
 
 /// Represents a `CHAR_INFO` which is used by console functions to read from and write to a console screen buffer.
 ///
 /// link: `https://docs.microsoft.com/en-us/windows/console/char-info-str`
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CharInfo{
     /// The char value
     pub char_value: char,
     /// The character attributes
     /// link: [https://docs.microsoft.com/en-us/windows/console/char-info-str#members]
-    pub attributes: u16
+    pub attributes: u16,
+    // The raw UTF-16 code unit when is half of a surrogate pair, in that case `char_value` is `REPLACEMENT_CHARACTER`.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    surrogate: Option<u16>
 }
 
 impl CharInfo{
@@ -18,19 +23,63 @@ impl CharInfo{
     #[inline]
     pub fn new(char_value: char, attributes: u16) -> Self {
         CharInfo{
-            char_value, attributes
+            char_value, attributes, surrogate: None
+        }
+    }
+
+    /// Creates a new `CharInfo` from a raw UTF-16 code unit.
+    ///
+    /// If the code unit is half of a surrogate pair the `char_value` is `REPLACEMENT_CHARACTER`
+    /// but the code unit is preserved and returned by [`raw_char`], so it can be written back
+    /// to the console without loss.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::char_info::CharInfo;
+    ///
+    /// let info = CharInfo::from_raw(0xD83D, 0x7);
+    /// assert_eq!(std::char::REPLACEMENT_CHARACTER, info.char_value);
+    /// assert_eq!(0xD83D, info.raw_char());
+    /// assert_eq!(CharInfo::new('A', 0x7), CharInfo::from_raw(0x41, 0x7));
+    /// ```
+    ///
+    /// [`raw_char`]: #method.raw_char
+    pub fn from_raw(unicode_char: u16, attributes: u16) -> Self {
+        match char::try_from(unicode_char as u32) {
+            Ok(char_value) => CharInfo::new(char_value, attributes),
+            Err(_) => CharInfo{
+                char_value: REPLACEMENT_CHARACTER,
+                attributes,
+                surrogate: Some(unicode_char)
+            }
+        }
+    }
+
+    /// Gets the raw UTF-16 code unit of this `CharInfo`.
+    ///
+    /// If the `char_value` needs a surrogate pair only the first code unit is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::char_info::CharInfo;
+    ///
+    /// assert_eq!(0x41, CharInfo::new('A', 0x7).raw_char());
+    /// ```
+    pub fn raw_char(&self) -> u16 {
+        match self.surrogate {
+            // The code unit is only valid while `char_value` was not changed
+            Some(unit) if self.char_value == REPLACEMENT_CHARACTER => unit,
+            _ => {
+                let mut buf = [0_u16; 2];
+                self.char_value.encode_utf16(&mut buf)[0]
+            }
         }
     }
 }
 
 impl From<CHAR_INFO> for CharInfo{
     fn from(info: CHAR_INFO) -> Self {
-        CharInfo{
-            char_value: {
-                char::try_from(unsafe { *info.Char.UnicodeChar() } as u32).unwrap()
-            },
-            attributes: info.Attributes
-        }
+        CharInfo::from_raw(unsafe { *info.Char.UnicodeChar() }, info.Attributes)
     }
 }
 
@@ -38,11 +87,47 @@ impl Into<CHAR_INFO> for CharInfo{
     fn into(self) -> CHAR_INFO {
         CHAR_INFO{
             Char: {
-                let mut buf : [u16; 1] = [0];
-                self.char_value.encode_utf16(buf.as_mut());
+                let buf : [u16; 1] = [self.raw_char()];
                 unsafe { std::mem::transmute(buf) }
             },
             Attributes: self.attributes
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn from_raw_surrogate_test(){
+        let info = CharInfo::from_raw(0xD800, 0x1F);
+        assert_eq!(REPLACEMENT_CHARACTER, info.char_value);
+        assert_eq!(0x1F, info.attributes);
+        assert_eq!(0xD800, info.raw_char());
+    }
+
+    #[test]
+    fn from_raw_char_test(){
+        let info = CharInfo::from_raw('x' as u16, 0x7);
+        assert_eq!(CharInfo::new('x', 0x7), info);
+        assert_eq!('x' as u16, info.raw_char());
+    }
+
+    #[test]
+    fn surrogate_round_trip_test(){
+        let info = CharInfo::from_raw(0xDC00, 0x1F);
+
+        let raw : CHAR_INFO = info.into();
+        assert_eq!(0xDC00, unsafe { *raw.Char.UnicodeChar() });
+        assert_eq!(0x1F, raw.Attributes);
+        assert_eq!(info, CharInfo::from(raw));
+    }
+
+    #[test]
+    fn raw_char_after_change_test(){
+        let mut info = CharInfo::from_raw(0xD800, 0x7);
+        info.char_value = 'z';
+        assert_eq!('z' as u16, info.raw_char());
+    }
+}
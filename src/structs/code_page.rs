@@ -0,0 +1,121 @@
+use std::hash::{Hash, Hasher};
+
+/// Represents a code page identifier used by the console to translate the input and output characters.
+///
+/// The code pages are compared by its identifier, so `CodePage::Other(65001)` is equals to `CodePage::Utf8`.
+///
+/// link: `https://docs.microsoft.com/en-us/windows/win32/intl/code-page-identifiers`
+#[derive(Debug, Copy, Clone, Eq)]
+pub enum CodePage {
+    /// OEM United States.
+    Oem437,
+    /// OEM Multilingual Latin 1; Western European (DOS).
+    Oem850,
+    /// OEM Russian; Cyrillic (DOS).
+    Oem866,
+    /// ANSI/OEM Japanese; Japanese (Shift-JIS).
+    ShiftJis,
+    /// Unicode UTF-16, little endian byte order.
+    Utf16,
+    /// ANSI Central European; Central European (Windows).
+    Windows1250,
+    /// ANSI Cyrillic; Cyrillic (Windows).
+    Windows1251,
+    /// ANSI Latin 1; Western European (Windows).
+    Windows1252,
+    /// Unicode (UTF-8).
+    Utf8,
+    /// Any other code page identifier.
+    Other(u32),
+}
+
+impl CodePage {
+    /// Checks if this is the `UTF-8` code page, including `CodePage::Other(65001)`.
+    #[inline]
+    pub fn is_utf8(&self) -> bool {
+        u32::from(*self) == 65001
+    }
+}
+
+impl PartialEq for CodePage {
+    fn eq(&self, other: &Self) -> bool {
+        u32::from(*self) == u32::from(*other)
+    }
+}
+
+impl Hash for CodePage {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        u32::from(*self).hash(state)
+    }
+}
+
+impl From<u32> for CodePage {
+    fn from(code_page: u32) -> Self {
+        match code_page {
+            437 => CodePage::Oem437,
+            850 => CodePage::Oem850,
+            866 => CodePage::Oem866,
+            932 => CodePage::ShiftJis,
+            1200 => CodePage::Utf16,
+            1250 => CodePage::Windows1250,
+            1251 => CodePage::Windows1251,
+            1252 => CodePage::Windows1252,
+            65001 => CodePage::Utf8,
+            other => CodePage::Other(other),
+        }
+    }
+}
+
+impl From<CodePage> for u32 {
+    fn from(code_page: CodePage) -> Self {
+        match code_page {
+            CodePage::Oem437 => 437,
+            CodePage::Oem850 => 850,
+            CodePage::Oem866 => 866,
+            CodePage::ShiftJis => 932,
+            CodePage::Utf16 => 1200,
+            CodePage::Windows1250 => 1250,
+            CodePage::Windows1251 => 1251,
+            CodePage::Windows1252 => 1252,
+            CodePage::Utf8 => 65001,
+            CodePage::Other(other) => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn from_u32_test(){
+        assert_eq!(CodePage::Utf8, CodePage::from(65001));
+        assert_eq!(CodePage::Oem437, CodePage::from(437));
+        assert_eq!(CodePage::Other(12000), CodePage::from(12000));
+    }
+
+    #[test]
+    fn into_u32_test(){
+        assert_eq!(65001, u32::from(CodePage::Utf8));
+        assert_eq!(1252, u32::from(CodePage::Windows1252));
+        assert_eq!(12000, u32::from(CodePage::Other(12000)));
+    }
+
+    #[test]
+    fn is_utf8_test(){
+        assert!(CodePage::Utf8.is_utf8());
+        assert!(CodePage::Other(65001).is_utf8());
+        assert!(!CodePage::Utf16.is_utf8());
+    }
+
+    #[test]
+    fn eq_by_identifier_test(){
+        assert_eq!(CodePage::Utf8, CodePage::Other(65001));
+        assert_eq!(CodePage::Other(437), CodePage::Oem437);
+        assert_ne!(CodePage::Utf8, CodePage::Other(1200));
+
+        let mut set = std::collections::HashSet::new();
+        set.insert(CodePage::Utf8);
+        assert!(set.contains(&CodePage::Other(65001)));
+    }
+}
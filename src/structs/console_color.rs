@@ -1,8 +1,10 @@
 use std::convert::TryFrom;
 use std::fmt::{Display, Error, Formatter, Debug};
+use std::io;
 
 /// Represents a color for the windows console.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConsoleColor {
     Black = 0,
     DarkBlue = 1,
@@ -54,6 +56,109 @@ impl ConsoleColor {
     pub fn as_background_color(&self) -> u16 {
         (*self as u16) << 4
     }
+
+    /// Gets the `SGR` parameter of the virtual terminal sequences that sets this as foreground color,
+    /// `30-37` for the dark colors and `90-97` for the bright colors.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::console_color::ConsoleColor;
+    /// use win32console::console::WinConsole;
+    ///
+    /// let code = ConsoleColor::Red.as_ansi_foreground();
+    /// WinConsole::output().write_utf8(format!("\x1b[{}mHello World!\x1b[39m", code).as_bytes());
+    /// ```
+    #[inline]
+    pub fn as_ansi_foreground(&self) -> u8 {
+        let value = *self as u8;
+        // The console stores the color bits as BGR and the virtual terminal sequences as RGB
+        let rgb = ((value & 0x4) >> 2) | (value & 0x2) | ((value & 0x1) << 2);
+
+        if value & 0x8 != 0 {
+            90 + rgb
+        } else {
+            30 + rgb
+        }
+    }
+
+    /// Gets the `SGR` parameter of the virtual terminal sequences that sets this as background color,
+    /// `40-47` for the dark colors and `100-107` for the bright colors.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::console_color::ConsoleColor;
+    /// use win32console::console::WinConsole;
+    ///
+    /// let code = ConsoleColor::Red.as_ansi_background();
+    /// WinConsole::output().write_utf8(format!("\x1b[{}mHello World!\x1b[49m", code).as_bytes());
+    /// ```
+    #[inline]
+    pub fn as_ansi_background(&self) -> u8 {
+        self.as_ansi_foreground() + 10
+    }
+
+    /// Gets the foreground color of the given `ConsoleTextAttribute`, stored in the bits 0-3.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::console_color::ConsoleColor;
+    ///
+    /// let attribute = ConsoleColor::Red.as_foreground_color() | ConsoleColor::Blue.as_background_color();
+    /// assert_eq!(ConsoleColor::Red, ConsoleColor::from_foreground_attribute(attribute).unwrap());
+    /// ```
+    #[inline]
+    pub fn from_foreground_attribute(attribute: u16) -> Result<ConsoleColor, ParseColorError> {
+        ConsoleColor::try_from(attribute & 0x0F)
+    }
+
+    /// Gets the background color of the given `ConsoleTextAttribute`, stored in the bits 4-7.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::console_color::ConsoleColor;
+    ///
+    /// let attribute = ConsoleColor::Red.as_foreground_color() | ConsoleColor::Blue.as_background_color();
+    /// assert_eq!(ConsoleColor::Blue, ConsoleColor::from_background_attribute(attribute).unwrap());
+    /// ```
+    #[inline]
+    pub fn from_background_attribute(attribute: u16) -> Result<ConsoleColor, ParseColorError> {
+        ConsoleColor::try_from((attribute & 0xF0) >> 4)
+    }
+
+    /// Swaps the foreground and background colors of the given `ConsoleTextAttribute`,
+    /// the other attributes are preserved.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::console_color::ConsoleColor;
+    ///
+    /// let attribute = ConsoleColor::Red.as_foreground_color() | ConsoleColor::Blue.as_background_color();
+    /// let swapped = ConsoleColor::swap_fg_bg(attribute);
+    /// assert_eq!(ConsoleColor::Blue.as_foreground_color() | ConsoleColor::Red.as_background_color(), swapped);
+    /// ```
+    #[inline]
+    pub fn swap_fg_bg(attribute: u16) -> u16 {
+        let foreground = attribute & 0x0F;
+        let background = (attribute & 0xF0) >> 4;
+        (attribute & !0xFF) | (foreground << 4) | background
+    }
+
+    /// Gets the inverse of this color by inverting its 4-bit value,
+    /// so dark colors are mapped to light colors and vice versa.
+    ///
+    /// This is useful to get a readable foreground color for a given background color.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::console_color::ConsoleColor;
+    ///
+    /// assert_eq!(ConsoleColor::White, ConsoleColor::Black.inverse());
+    /// assert_eq!(ConsoleColor::Yellow, ConsoleColor::DarkBlue.inverse());
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> ConsoleColor {
+        ConsoleColor::try_from(0xF ^ (*self as u16)).unwrap()
+    }
 }
 
 /// Represents an error when parsing a color, and contains the invalid `ConsoleTextAttribute` value.
@@ -64,6 +169,21 @@ impl Debug for ParseColorError{
     }
 }
 
+impl Display for ParseColorError{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid color value: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseColorError{}
+
+impl From<ParseColorError> for io::Error{
+    #[inline]
+    fn from(error: ParseColorError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, error)
+    }
+}
+
 impl TryFrom<u16> for ConsoleColor{
     type Error = ParseColorError;
 
@@ -138,6 +258,61 @@ mod tests{
         assert_eq!(ConsoleTextAttribute::BACKGROUND_RED, color.as_background_color());
     }
 
+    #[test]
+    fn swap_fg_bg_test(){
+        let attribute = ConsoleColor::Yellow.as_foreground_color()
+            | ConsoleColor::DarkBlue.as_background_color()
+            | ConsoleTextAttribute::COMMON_LVB_UNDERSCORE;
+
+        let swapped = ConsoleColor::swap_fg_bg(attribute);
+        assert_eq!(ConsoleColor::DarkBlue.as_foreground_color()
+                       | ConsoleColor::Yellow.as_background_color()
+                       | ConsoleTextAttribute::COMMON_LVB_UNDERSCORE, swapped);
+        assert_eq!(attribute, ConsoleColor::swap_fg_bg(swapped));
+    }
+
+    #[test]
+    fn from_foreground_attribute_test(){
+        let attribute = ConsoleColor::Yellow.as_foreground_color()
+            | ConsoleColor::DarkBlue.as_background_color()
+            | ConsoleTextAttribute::COMMON_LVB_UNDERSCORE;
+
+        assert_eq!(ConsoleColor::Yellow, ConsoleColor::from_foreground_attribute(attribute).unwrap());
+        assert_eq!(ConsoleColor::Black, ConsoleColor::from_foreground_attribute(0xF0).unwrap());
+    }
+
+    #[test]
+    fn from_background_attribute_test(){
+        let attribute = ConsoleColor::Yellow.as_foreground_color()
+            | ConsoleColor::DarkBlue.as_background_color()
+            | ConsoleTextAttribute::COMMON_LVB_UNDERSCORE;
+
+        assert_eq!(ConsoleColor::DarkBlue, ConsoleColor::from_background_attribute(attribute).unwrap());
+        assert_eq!(ConsoleColor::White, ConsoleColor::from_background_attribute(0xF0).unwrap());
+    }
+
+    #[test]
+    fn inverse_test(){
+        assert_eq!(ConsoleColor::White, ConsoleColor::Black.inverse());
+        assert_eq!(ConsoleColor::Black, ConsoleColor::White.inverse());
+        assert_eq!(ConsoleColor::DarkGray, ConsoleColor::Gray.inverse());
+        assert_eq!(ConsoleColor::Cyan, ConsoleColor::DarkRed.inverse());
+
+        for value in 0..16{
+            let color = ConsoleColor::try_from(value).unwrap();
+            assert_eq!(color, color.inverse().inverse());
+        }
+    }
+
+    #[test]
+    fn parse_color_error_into_io_error_test(){
+        let error = ConsoleColor::try_from(16).unwrap_err();
+        let io_error = std::io::Error::from(error);
+
+        assert_eq!(std::io::ErrorKind::InvalidData, io_error.kind());
+        assert_eq!("Invalid color value: 16", io_error.to_string());
+    }
+
     #[test]
     fn try_from_test1(){
         let color = ConsoleColor::try_from(4).ok();
@@ -151,4 +326,25 @@ mod tests{
         assert!(color.is_some());
         assert_eq!(ConsoleColor::Red, color.unwrap())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn as_ansi_foreground_test(){
+        assert_eq!(30, ConsoleColor::Black.as_ansi_foreground());
+        assert_eq!(31, ConsoleColor::DarkRed.as_ansi_foreground());
+        assert_eq!(33, ConsoleColor::DarkYellow.as_ansi_foreground());
+        assert_eq!(34, ConsoleColor::DarkBlue.as_ansi_foreground());
+        assert_eq!(37, ConsoleColor::Gray.as_ansi_foreground());
+        assert_eq!(90, ConsoleColor::DarkGray.as_ansi_foreground());
+        assert_eq!(92, ConsoleColor::Green.as_ansi_foreground());
+        assert_eq!(96, ConsoleColor::Cyan.as_ansi_foreground());
+        assert_eq!(97, ConsoleColor::White.as_ansi_foreground());
+    }
+
+    #[test]
+    fn as_ansi_background_test(){
+        assert_eq!(40, ConsoleColor::Black.as_ansi_background());
+        assert_eq!(45, ConsoleColor::DarkMagenta.as_ansi_background());
+        assert_eq!(101, ConsoleColor::Red.as_ansi_background());
+        assert_eq!(107, ConsoleColor::White.as_ansi_background());
+    }
+}
@@ -1,8 +1,10 @@
 use winapi::um::wincon::CONSOLE_CURSOR_INFO;
+use std::fmt::{Display, Formatter, Error};
 
 /// Represents a `CONSOLE_CURSOR_INFO` which contains information about the console cursor.
 ///
 /// link: `https://docs.microsoft.com/en-us/windows/console/console-cursor-info-str`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct ConsoleCursorInfo{
     /// The percentage of the character cell that is filled by the cursor.
     /// This value is between 1 and 100. The cursor appearance varies,
@@ -12,6 +14,13 @@ pub struct ConsoleCursorInfo{
     pub visible: bool
 }
 
+impl Display for ConsoleCursorInfo{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_fmt(format_args!("cursor(size: {}%, visible: {})", self.size, self.visible))
+    }
+}
+
 impl Into<CONSOLE_CURSOR_INFO> for ConsoleCursorInfo{
     #[inline]
     fn into(self) -> CONSOLE_CURSOR_INFO {
@@ -30,4 +39,22 @@ impl From<CONSOLE_CURSOR_INFO> for ConsoleCursorInfo{
             visible: if info.bVisible == 0 { false } else { true }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn cursor_info_display_test(){
+        let info = ConsoleCursorInfo{ size: 25, visible: true };
+        assert_eq!("cursor(size: 25%, visible: true)", info.to_string());
+    }
+
+    #[test]
+    fn cursor_info_into_test(){
+        let info = ConsoleCursorInfo{ size: 100, visible: false };
+        let raw_info : CONSOLE_CURSOR_INFO = info.into();
+        assert_eq!(info, ConsoleCursorInfo::from(raw_info));
+    }
+}
@@ -5,6 +5,7 @@ use winapi::um::wincon::CONSOLE_FONT_INFO;
 ///
 /// link `https://docs.microsoft.com/en-us/windows/console/console-font-info-str`
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConsoleFontInfo{
     /// The index of the font in the system's console font table.
     pub font_index: u32,
@@ -6,13 +6,14 @@ use winapi::um::wingdi::LF_FACESIZE;
 ///
 /// link: `https://docs.microsoft.com/en-us/windows/console/console-font-infoex`
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConsoleFontInfoEx {
     /// Size this struct in bytes.
     ///
-    /// The size can be obtained using:
+    /// The size must be the size of the winapi `CONSOLE_FONT_INFOEX` struct, which can be obtained using:
     /// ```
-    /// use win32console::structs::console_font_info_ex::*;
-    /// let size = std::mem::size_of::<ConsoleFontInfoEx>();
+    /// use winapi::um::wincon::CONSOLE_FONT_INFOEX;
+    /// let size = std::mem::size_of::<CONSOLE_FONT_INFOEX>();
     /// ```
     pub size: u32,
     /// The index of the font in the system's console font table.
@@ -27,6 +28,42 @@ pub struct ConsoleFontInfoEx {
     pub face_name: [u16; LF_FACESIZE],
 }
 
+impl ConsoleFontInfoEx {
+    /// Gets the name of the typeface, the characters after the first `NUL` are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// let info = WinConsole::output().get_font_ex(false).unwrap();
+    /// WinConsole::output().write_utf8(info.font_name().as_bytes()).unwrap();
+    /// ```
+    pub fn font_name(&self) -> String {
+        let len = self.face_name.iter()
+            .position(|c| *c == 0)
+            .unwrap_or(LF_FACESIZE);
+
+        String::from_utf16_lossy(&self.face_name[..len])
+    }
+
+    /// Sets the name of the typeface, if the name is larger than `LF_FACESIZE - 1` utf-16 characters
+    /// is truncated, the name is always terminated with `NUL`.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// let mut info = WinConsole::output().get_font_ex(false).unwrap();
+    /// info.set_font_name("Consolas");
+    /// assert_eq!("Consolas", info.font_name());
+    /// ```
+    pub fn set_font_name(&mut self, name: &str) {
+        self.face_name = [0; LF_FACESIZE];
+
+        for (dest, c) in self.face_name[..LF_FACESIZE - 1].iter_mut().zip(name.encode_utf16()) {
+            *dest = c;
+        }
+    }
+}
+
 impl From<&CONSOLE_FONT_INFOEX> for ConsoleFontInfoEx {
     #[inline]
     fn from(info: &CONSOLE_FONT_INFOEX) -> Self {
@@ -54,3 +91,30 @@ impl Into<CONSOLE_FONT_INFOEX> for ConsoleFontInfoEx {
         }
     }
 }
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn font_name_round_trip_test(){
+        let mut info : ConsoleFontInfoEx = unsafe { std::mem::zeroed() };
+        assert_eq!("", info.font_name());
+
+        info.set_font_name("Consolas");
+        assert_eq!("Consolas", info.font_name());
+
+        info.set_font_name("Lucida");
+        assert_eq!("Lucida", info.font_name());
+    }
+
+    #[test]
+    fn set_font_name_truncate_test(){
+        let mut info : ConsoleFontInfoEx = unsafe { std::mem::zeroed() };
+        let name = "a".repeat(LF_FACESIZE + 10);
+        info.set_font_name(&name);
+
+        assert_eq!(&name[..LF_FACESIZE - 1], info.font_name());
+        assert_eq!(0, info.face_name[LF_FACESIZE - 1]);
+    }
+}
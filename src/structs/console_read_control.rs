@@ -1,3 +1,4 @@
+use std::io::{Error, ErrorKind, Result};
 use crate::structs::input_event::ControlKeyState;
 use winapi::um::wincon::CONSOLE_READCONSOLE_CONTROL;
 
@@ -52,6 +53,38 @@ impl ConsoleReadControl{
             control_key_state: ControlKeyState::new(0)
         }
     }
+
+    /// Creates a new `ConsoleReadControl` that completes the read when any of the given
+    /// control characters is entered, the `ctrl_wakeup_mask` is built by setting the bit
+    /// of each character.
+    ///
+    /// # Errors
+    /// - `ErrorKind::InvalidInput` if any of the characters is not a control character in the range `0..=31`.
+    ///
+    /// # Examples
+    /// ```
+    /// use win32console::structs::console_read_control::ConsoleReadControl;
+    ///
+    /// // Completes the read on `Tab` or `Ctrl+Z` press.
+    /// let control = ConsoleReadControl::wakeup_on_chars(&['\t', '\x1A']).unwrap();
+    /// assert_eq!((1 << 9) | (1 << 26), control.ctrl_wakeup_mask);
+    /// ```
+    pub fn wakeup_on_chars(chars: &[char]) -> Result<Self>{
+        let mut mask = 0;
+
+        for c in chars {
+            let value = *c as u32;
+            if value >= 32 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("'{}' is not a control character in the range 0..=31", c.escape_debug())));
+            }
+
+            mask |= 1 << value;
+        }
+
+        Ok(ConsoleReadControl::new_with_mask(mask))
+    }
 }
 
 impl From<CONSOLE_READCONSOLE_CONTROL> for ConsoleReadControl{
@@ -76,4 +109,25 @@ impl Into<CONSOLE_READCONSOLE_CONTROL> for ConsoleReadControl{
             dwControlKeyState: self.control_key_state.get_state()
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn wakeup_on_chars_test(){
+        let control = ConsoleReadControl::wakeup_on_chars(&['\t', '\x1A']).unwrap();
+        assert_eq!(0x0400_0200, control.ctrl_wakeup_mask);
+        assert_eq!(0, control.initial_chars);
+
+        let control = ConsoleReadControl::wakeup_on_chars(&['\0', '\x1F']).unwrap();
+        assert_eq!(0x8000_0001, control.ctrl_wakeup_mask);
+    }
+
+    #[test]
+    fn wakeup_on_chars_non_control_test(){
+        let error = ConsoleReadControl::wakeup_on_chars(&['a']).unwrap_err();
+        assert_eq!(ErrorKind::InvalidInput, error.kind());
+    }
+}
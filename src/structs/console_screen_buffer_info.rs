@@ -7,6 +7,7 @@ use crate::structs::small_rect::SmallRect;
 ///
 /// link: `https://docs.microsoft.com/en-us/windows/console/console-screen-buffer-info-str`
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConsoleScreenBufferInfo {
     /// Size of the screen buffer in rows and columns.
     pub screen_buffer_size: Coord,
@@ -20,6 +21,24 @@ pub struct ConsoleScreenBufferInfo {
     pub maximum_window_size: Coord,
 }
 
+impl ConsoleScreenBufferInfo{
+    /// Gets the width of the console window in columns, this is the inclusive width of [`window`].
+    ///
+    /// [`window`]: #structfield.window
+    #[inline]
+    pub fn window_width(&self) -> i16{
+        self.window.width()
+    }
+
+    /// Gets the height of the console window in rows, this is the inclusive height of [`window`].
+    ///
+    /// [`window`]: #structfield.window
+    #[inline]
+    pub fn window_height(&self) -> i16{
+        self.window.height()
+    }
+}
+
 impl From<CONSOLE_SCREEN_BUFFER_INFO> for ConsoleScreenBufferInfo {
     #[inline]
     fn from(info: CONSOLE_SCREEN_BUFFER_INFO) -> Self {
@@ -44,4 +63,18 @@ impl Into<CONSOLE_SCREEN_BUFFER_INFO> for ConsoleScreenBufferInfo {
             dwMaximumWindowSize: self.maximum_window_size.into(),
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn window_size_test(){
+        let mut info : ConsoleScreenBufferInfo = unsafe { std::mem::zeroed() };
+        info.window = SmallRect::new(0, 10, 119, 39);
+
+        assert_eq!(120, info.window_width());
+        assert_eq!(30, info.window_height());
+    }
+}
@@ -7,6 +7,7 @@ use crate::structs::small_rect::SmallRect;
 ///
 /// link: `https://docs.microsoft.com/en-us/windows/console/console-screen-buffer-infoex`
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConsoleScreenBufferInfoEx {
     /// Size this struct in bytes.
     ///
@@ -36,6 +37,24 @@ pub struct ConsoleScreenBufferInfoEx {
     pub color_table: [u32; 16],
 }
 
+impl ConsoleScreenBufferInfoEx{
+    /// Gets the width of the console window in columns, this is the inclusive width of [`window`].
+    ///
+    /// [`window`]: #structfield.window
+    #[inline]
+    pub fn window_width(&self) -> i16{
+        self.window.width()
+    }
+
+    /// Gets the height of the console window in rows, this is the inclusive height of [`window`].
+    ///
+    /// [`window`]: #structfield.window
+    #[inline]
+    pub fn window_height(&self) -> i16{
+        self.window.height()
+    }
+}
+
 impl From<CONSOLE_SCREEN_BUFFER_INFOEX> for ConsoleScreenBufferInfoEx {
     #[inline]
     fn from(info: CONSOLE_SCREEN_BUFFER_INFOEX) -> Self {
@@ -69,3 +88,28 @@ impl Into<CONSOLE_SCREEN_BUFFER_INFOEX> for ConsoleScreenBufferInfoEx {
         }
     }
 }
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn window_size_test(){
+        let mut info : ConsoleScreenBufferInfoEx = unsafe { std::mem::zeroed() };
+        info.window = SmallRect::new(0, 10, 119, 39);
+
+        assert_eq!(120, info.window_width());
+        assert_eq!(30, info.window_height());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip_test(){
+        let mut info : ConsoleScreenBufferInfoEx = unsafe { std::mem::zeroed() };
+        info.window = SmallRect::new(0, 10, 119, 39);
+        info.color_table[1] = 0x00FF_8000;
+
+        let json = serde_json::to_string(&info).unwrap();
+        assert_eq!(info, serde_json::from_str::<ConsoleScreenBufferInfoEx>(&json).unwrap());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::structs::coord::Coord;
 use winapi::um::wincon::{CONSOLE_SELECTION_INFO};
 use crate::structs::small_rect::SmallRect;
+use std::fmt::{Display, Formatter, Error};
 
 /// Represents a `CONSOLE_SELECTION_INFO` which contains information for a console selection.
 ///
@@ -74,6 +75,28 @@ impl SelectionState {
     }
 }
 
+impl Display for SelectionState{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if self.no_selection(){
+            return f.write_str("CONSOLE_NO_SELECTION");
+        }
+
+        let flags = [
+            (SelectionState::CONSOLE_SELECTION_IN_PROGRESS, "CONSOLE_SELECTION_IN_PROGRESS"),
+            (SelectionState::CONSOLE_SELECTION_NOT_EMPTY, "CONSOLE_SELECTION_NOT_EMPTY"),
+            (SelectionState::CONSOLE_MOUSE_SELECTION, "CONSOLE_MOUSE_SELECTION"),
+            (SelectionState::CONSOLE_MOUSE_DOWN, "CONSOLE_MOUSE_DOWN"),
+        ];
+
+        let names = flags.iter()
+            .filter(|(flag, _)| self.has_state(*flag))
+            .map(|(_, name)| *name)
+            .collect::<Vec<&str>>();
+
+        f.write_str(&names.join(" | "))
+    }
+}
+
 impl Into<CONSOLE_SELECTION_INFO> for ConsoleSelectionInfo {
     #[inline]
     fn into(self) -> CONSOLE_SELECTION_INFO {
@@ -95,3 +118,19 @@ impl From<CONSOLE_SELECTION_INFO> for ConsoleSelectionInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn selection_state_display_test(){
+        assert_eq!("CONSOLE_NO_SELECTION", SelectionState(SelectionState::CONSOLE_NO_SELECTION).to_string());
+        assert_eq!("CONSOLE_SELECTION_IN_PROGRESS", SelectionState(SelectionState::CONSOLE_SELECTION_IN_PROGRESS).to_string());
+
+        let state = SelectionState(SelectionState::CONSOLE_SELECTION_IN_PROGRESS
+            | SelectionState::CONSOLE_MOUSE_SELECTION
+            | SelectionState::CONSOLE_MOUSE_DOWN);
+        assert_eq!("CONSOLE_SELECTION_IN_PROGRESS | CONSOLE_MOUSE_SELECTION | CONSOLE_MOUSE_DOWN", state.to_string());
+    }
+}
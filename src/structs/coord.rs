@@ -1,5 +1,7 @@
 use winapi::um::wincon::COORD;
+use crate::structs::small_rect::SmallRect;
 use std::fmt::Display;
+use std::cmp::Ordering;
 use winapi::_core::fmt::{Formatter, Error};
 
 /// Represents a `COORD` which is the position of the characters cell in the console screen buffer,
@@ -7,6 +9,7 @@ use winapi::_core::fmt::{Formatter, Error};
 ///
 /// link: `https://docs.microsoft.com/en-us/windows/console/coord-str`
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coord {
     /// x axis position
     pub x: i16,
@@ -18,12 +21,31 @@ impl Coord {
     /// A default value coord, where x and y are zero.
     pub const ZERO: Coord = Coord { x: 0, y: 0 };
 
+    /// The origin of the screen buffer (0,0), same as [`ZERO`] but with a clearer intent for positions.
+    ///
+    /// [`ZERO`]: #associatedconstant.ZERO
+    pub const ORIGIN: Coord = Coord::ZERO;
+
     /// Create a new size instance with the given x and y.
     #[inline]
     pub fn new(x: i16, y: i16) -> Coord {
         Coord { x, y }
     }
 
+    /// Checks if this `Coord` is the origin (0,0).
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::coord::Coord;
+    ///
+    /// assert!(Coord::ORIGIN.is_origin());
+    /// assert!(!Coord::new(0, 1).is_origin());
+    /// ```
+    #[inline]
+    pub fn is_origin(&self) -> bool{
+        *self == Coord::ORIGIN
+    }
+
     /// Gets this `Coord` with a new `x` value.
     #[inline]
     pub fn with_x(&self, x: i16) -> Coord{
@@ -41,6 +63,76 @@ impl Coord {
             y
         }
     }
+
+    /// Gets this `Coord` clamped to the edges of the given bounds,
+    /// where `right` and `bottom` are inclusive.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::coord::Coord;
+    /// use win32console::structs::small_rect::SmallRect;
+    ///
+    /// let bounds = SmallRect::new(0, 0, 79, 24);
+    /// assert_eq!(Coord::new(79, 0), Coord::new(100, -5).clamp_to(bounds));
+    /// ```
+    #[inline]
+    pub fn clamp_to(&self, bounds: SmallRect) -> Coord{
+        Coord{
+            x: self.x.max(bounds.left).min(bounds.right),
+            y: self.y.max(bounds.top).min(bounds.bottom)
+        }
+    }
+
+    /// Adds the given `Coord` to this `Coord`, clamping each axis to the bounds of `i16`
+    /// instead of wrapping around.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::coord::Coord;
+    ///
+    /// assert_eq!(Coord::new(5, 7), Coord::new(2, 3).saturating_add(Coord::new(3, 4)));
+    /// assert_eq!(Coord::new(i16::MAX, 1), Coord::new(i16::MAX - 1, 0).saturating_add(Coord::new(10, 1)));
+    /// ```
+    #[inline]
+    pub fn saturating_add(&self, other: Coord) -> Coord{
+        Coord{
+            x: self.x.saturating_add(other.x),
+            y: self.y.saturating_add(other.y)
+        }
+    }
+
+    /// Subtracts the given `Coord` from this `Coord`, clamping each axis to the bounds of `i16`
+    /// instead of wrapping around.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::coord::Coord;
+    ///
+    /// assert_eq!(Coord::new(2, 3), Coord::new(5, 7).saturating_sub(Coord::new(3, 4)));
+    /// assert_eq!(Coord::new(i16::MIN, 0), Coord::new(i16::MIN + 1, 1).saturating_sub(Coord::new(10, 1)));
+    /// ```
+    #[inline]
+    pub fn saturating_sub(&self, other: Coord) -> Coord{
+        Coord{
+            x: self.x.saturating_sub(other.x),
+            y: self.y.saturating_sub(other.y)
+        }
+    }
+
+    /// Compares this `Coord` with other in reading order, first by `y` and then by `x`,
+    /// so a cell is less than other if appears before it in the screen buffer.
+    ///
+    /// # Example
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use win32console::structs::coord::Coord;
+    ///
+    /// assert_eq!(Ordering::Less, Coord::new(79, 0).reading_order_cmp(&Coord::new(0, 1)));
+    /// ```
+    #[inline]
+    pub fn reading_order_cmp(&self, other: &Coord) -> Ordering{
+        self.y.cmp(&other.y).then(self.x.cmp(&other.x))
+    }
 }
 
 impl Display for Coord{
@@ -66,3 +158,61 @@ impl Into<COORD> for Coord {
         }
     }
 }
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn clamp_to_negative_test(){
+        let bounds = SmallRect::new(0, 0, 79, 24);
+        assert_eq!(Coord::new(0, 0), Coord::new(-3, -10).clamp_to(bounds));
+    }
+
+    #[test]
+    fn clamp_to_out_of_range_test(){
+        let bounds = SmallRect::new(0, 0, 79, 24);
+        assert_eq!(Coord::new(79, 24), Coord::new(80, 300).clamp_to(bounds));
+        assert_eq!(Coord::new(5, 6), Coord::new(5, 6).clamp_to(bounds));
+    }
+
+    #[test]
+    fn reading_order_cmp_same_row_test(){
+        assert_eq!(Ordering::Less, Coord::new(2, 5).reading_order_cmp(&Coord::new(3, 5)));
+        assert_eq!(Ordering::Greater, Coord::new(3, 5).reading_order_cmp(&Coord::new(2, 5)));
+        assert_eq!(Ordering::Equal, Coord::new(3, 5).reading_order_cmp(&Coord::new(3, 5)));
+    }
+
+    #[test]
+    fn reading_order_cmp_cross_row_test(){
+        assert_eq!(Ordering::Less, Coord::new(79, 0).reading_order_cmp(&Coord::new(0, 1)));
+        assert_eq!(Ordering::Greater, Coord::new(0, 1).reading_order_cmp(&Coord::new(79, 0)));
+
+        let mut cells = vec![Coord::new(1, 1), Coord::new(0, 2), Coord::new(5, 0)];
+        cells.sort_by(|a, b| a.reading_order_cmp(b));
+        assert_eq!(vec![Coord::new(5, 0), Coord::new(1, 1), Coord::new(0, 2)], cells);
+    }
+
+    #[test]
+    fn is_origin_test(){
+        assert!(Coord::ORIGIN.is_origin());
+        assert!(Coord::ZERO.is_origin());
+        assert!(Coord::default().is_origin());
+        assert!(!Coord::new(1, 0).is_origin());
+        assert!(!Coord::new(0, -1).is_origin());
+    }
+
+    #[test]
+    fn saturating_add_test(){
+        assert_eq!(Coord::new(i16::MAX, i16::MAX), Coord::new(i16::MAX, 32000).saturating_add(Coord::new(1, 1000)));
+        assert_eq!(Coord::new(i16::MAX, -1), Coord::new(i16::MAX - 1, 0).saturating_add(Coord::new(1, -1)));
+        assert_eq!(Coord::new(i16::MIN, 0), Coord::new(i16::MIN, 5).saturating_add(Coord::new(-1, -5)));
+    }
+
+    #[test]
+    fn saturating_sub_test(){
+        assert_eq!(Coord::new(i16::MIN, i16::MIN), Coord::new(i16::MIN, -32000).saturating_sub(Coord::new(1, 1000)));
+        assert_eq!(Coord::new(i16::MIN, 1), Coord::new(i16::MIN + 1, 0).saturating_sub(Coord::new(1, -1)));
+        assert_eq!(Coord::new(i16::MAX, 0), Coord::new(i16::MAX, 0).saturating_sub(Coord::new(-1, 0)));
+    }
+}
@@ -1,7 +1,10 @@
 use std::ops::Deref;
+use std::io::{Error, Result};
+use std::ptr::null_mut;
 use winapi::{
-    um::handleapi::{CloseHandle, INVALID_HANDLE_VALUE},
-    um::winnt::HANDLE
+    um::handleapi::{CloseHandle, DuplicateHandle, INVALID_HANDLE_VALUE},
+    um::processthreadsapi::GetCurrentProcess,
+    um::winnt::{HANDLE, DUPLICATE_SAME_ACCESS}
 };
 
 /// Wraps a windows [HANDLE].
@@ -106,6 +109,85 @@ impl Handle {
         self.0.handle
     }
 
+    /// Consumes this `Handle` and returns the underlying `HANDLE` without close it,
+    /// so the ownership of the handle can be transferred to other library.
+    ///
+    /// The caller is responsible to close the returned handle,
+    /// the ownership can be reclaimed by calling [`new_owned`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usages:
+    /// ```
+    /// use win32console::structs::handle::Handle;
+    /// use winapi::um::fileapi::{CreateFileW, OPEN_EXISTING};
+    /// use winapi::um::handleapi::GetHandleInformation;
+    /// use winapi::um::winnt::{GENERIC_READ, FILE_SHARE_READ, FILE_SHARE_WRITE, GENERIC_WRITE};
+    /// use std::ptr::null_mut;
+    ///
+    /// let file_name: Vec<u16> = "CONOUT$\0".encode_utf16().collect();
+    /// let handle = Handle::new_owned(unsafe { CreateFileW(
+    ///                file_name.as_ptr(),
+    ///                GENERIC_READ | GENERIC_WRITE,
+    ///                FILE_SHARE_READ | FILE_SHARE_WRITE,
+    ///                null_mut(),
+    ///                OPEN_EXISTING,
+    ///                0,
+    ///                null_mut(),
+    ///            ) });
+    ///
+    /// // The handle is not closed
+    /// let raw_handle = handle.into_raw();
+    /// let mut flags = 0;
+    /// assert_ne!(0, unsafe { GetHandleInformation(raw_handle, &mut flags) });
+    ///
+    /// // Reclaims the ownership, the handle is closed on drop
+    /// drop(Handle::new_owned(raw_handle));
+    /// assert_eq!(0, unsafe { GetHandleInformation(raw_handle, &mut flags) });
+    /// ```
+    ///
+    /// [`new_owned`]: #method.new_owned
+    #[inline]
+    pub fn into_raw(self) -> HANDLE {
+        let handle = self.0.handle;
+        std::mem::forget(self);
+        handle
+    }
+
+    /// Creates a new owned `Handle` by duplicating the underlying `HANDLE`, the new handle refers to the
+    /// same object and have the same access, but is closed independently of this handle.
+    ///
+    /// Wraps a call to [DuplicateHandle](https://docs.microsoft.com/en-us/windows/win32/api/handleapi/nf-handleapi-duplicatehandle).
+    ///
+    /// # Errors
+    /// - If the handle is an invalid handle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usages:
+    /// ```
+    /// use win32console::structs::handle::Handle;
+    /// use winapi::um::processenv::GetStdHandle;
+    /// use winapi::um::winbase::STD_OUTPUT_HANDLE;
+    ///
+    /// let handle = Handle::new(unsafe { GetStdHandle(STD_OUTPUT_HANDLE) });
+    /// let cloned = handle.try_clone().unwrap();
+    /// assert!(cloned.is_valid());
+    /// assert_ne!(handle.get_raw(), cloned.get_raw());
+    /// ```
+    pub fn try_clone(&self) -> Result<Handle> {
+        let mut handle = null_mut();
+
+        unsafe {
+            let current_process = GetCurrentProcess();
+            if DuplicateHandle(current_process, self.0.handle, current_process, &mut handle, 0, 0, DUPLICATE_SAME_ACCESS) == 0 {
+                Err(Error::last_os_error())
+            } else {
+                Ok(Handle::new_owned(handle))
+            }
+        }
+    }
+
     /// Compare this handle to [INVALID_HANDLE_VALUE] to determines if the handle is valid.
     ///
     /// # Examples:
@@ -85,6 +85,56 @@ pub enum EventFlags {
     MouseHwheeled = 0x0008,
 }
 
+impl KeyEventRecord {
+    /// Returns an iterator that yields a copy of this record for each time the key was repeated,
+    /// each one with a `repeat_count` of 1.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::input_record::InputRecord::KeyEvent;
+    ///
+    /// let mut text = String::new();
+    /// if let KeyEvent(e) = WinConsole::input().read_single_input().unwrap(){
+    ///     for key in e.repeated(){
+    ///         text.push(key.u_char);
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn repeated(&self) -> impl Iterator<Item = KeyEventRecord> {
+        let single = KeyEventRecord { repeat_count: 1, ..*self };
+        (0..self.repeat_count).map(move |_| single)
+    }
+}
+
+impl MouseEventRecord {
+    /// Checks if this event is a mouse button press, a `PressOrRelease` event with some button down.
+    #[inline]
+    pub fn is_press(&self) -> bool {
+        self.event_flags == EventFlags::PressOrRelease && !self.button_state.release_button()
+    }
+
+    /// Checks if this event is a mouse button release, a `PressOrRelease` event with no buttons down.
+    #[inline]
+    pub fn is_release(&self) -> bool {
+        self.event_flags == EventFlags::PressOrRelease && self.button_state.release_button()
+    }
+
+    /// Checks if this event is a mouse drag, a `MouseMoved` event with some button down.
+    #[inline]
+    pub fn is_drag(&self) -> bool {
+        self.event_flags == EventFlags::MouseMoved && !self.button_state.release_button()
+    }
+
+    /// Checks if this event is a vertical or horizontal mouse wheel movement,
+    /// the direction can be obtained from the `button_state`.
+    #[inline]
+    pub fn is_wheel(&self) -> bool {
+        self.event_flags == EventFlags::MouseWheeled || self.event_flags == EventFlags::MouseHwheeled
+    }
+}
+
 impl ControlKeyState {
     /// The right ALT key is pressed.
     pub const RIGHT_ALT_PRESSED: u32 = 0x0001;
@@ -111,6 +161,38 @@ impl ControlKeyState {
         ControlKeyState(state)
     }
 
+    /// Creates a new [ControlKeyState] without any control key pressed.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::input_event::ControlKeyState;
+    /// let state = ControlKeyState::none().with_shift().with_ctrl();
+    /// assert!(state.is_shift_pressed());
+    /// assert!(state.is_ctrl_pressed());
+    /// ```
+    #[inline]
+    pub fn none() -> Self{
+        ControlKeyState(0)
+    }
+
+    /// Returns a copy of this state with the `SHIFT` key pressed.
+    #[inline]
+    pub fn with_shift(self) -> Self{
+        ControlKeyState(self.0 | ControlKeyState::SHIFT_PRESSED)
+    }
+
+    /// Returns a copy of this state with the left `CTRL` key pressed.
+    #[inline]
+    pub fn with_ctrl(self) -> Self{
+        ControlKeyState(self.0 | ControlKeyState::LEFT_CTRL_PRESSED)
+    }
+
+    /// Returns a copy of this state with the left `ALT` key pressed.
+    #[inline]
+    pub fn with_alt(self) -> Self{
+        ControlKeyState(self.0 | ControlKeyState::LEFT_ALT_PRESSED)
+    }
+
     /// Checks whether this state contains the specified.
     ///
     /// # Example
@@ -330,6 +412,38 @@ mod tests{
         });
     }
 
+    #[test]
+    fn key_event_repeated_test(){
+        let mut key_event : KeyEventRecord = unsafe { std::mem::zeroed() };
+        key_event.key_down = true;
+        key_event.u_char = 'a';
+        key_event.repeat_count = 3;
+
+        let keys = key_event.repeated().collect::<Vec<KeyEventRecord>>();
+        assert_eq!(3, keys.len());
+
+        for key in keys{
+            assert_eq!(1, key.repeat_count);
+            assert_eq!('a', key.u_char);
+            assert!(key.key_down);
+        }
+    }
+
+    #[test]
+    fn control_key_state_builder_test(){
+        let state = ControlKeyState::none();
+        assert_eq!(0, state.get_state());
+
+        let state = state.with_shift().with_ctrl();
+        assert!(state.is_shift_pressed());
+        assert!(state.is_ctrl_pressed());
+        assert!(!state.is_alt_pressed());
+
+        let state = state.with_alt();
+        assert!(state.is_alt_pressed());
+        assert_eq!(ControlKeyState::SHIFT_PRESSED | ControlKeyState::LEFT_CTRL_PRESSED | ControlKeyState::LEFT_ALT_PRESSED, state.get_state());
+    }
+
     #[test]
     fn mouse_event_into_test(){
         let mouse_event : MouseEventRecord = unsafe { std::mem::zeroed() };
@@ -340,4 +454,49 @@ mod tests{
         assert_eq!(mouse_event.button_state.get_state() as u32, raw_mouse_event.dwButtonState);
         assert_eq!(mouse_event.mouse_position, Coord::from(raw_mouse_event.dwMousePosition));
     }
+
+    fn mouse_event(event_flags: EventFlags, button_state: u32) -> MouseEventRecord{
+        MouseEventRecord{
+            mouse_position: Coord::new(0, 0),
+            button_state: ButtonState::from(button_state),
+            control_key_state: ControlKeyState::none(),
+            event_flags
+        }
+    }
+
+    #[test]
+    fn mouse_event_press_release_test(){
+        let press = mouse_event(EventFlags::PressOrRelease, FROM_LEFT_1ST_BUTTON_PRESSED);
+        assert!(press.is_press());
+        assert!(!press.is_release());
+        assert!(!press.is_drag());
+
+        let release = mouse_event(EventFlags::PressOrRelease, 0);
+        assert!(release.is_release());
+        assert!(!release.is_press());
+        assert!(!release.is_wheel());
+    }
+
+    #[test]
+    fn mouse_event_drag_test(){
+        let drag = mouse_event(EventFlags::MouseMoved, RIGHTMOST_BUTTON_PRESSED);
+        assert!(drag.is_drag());
+        assert!(!drag.is_press());
+
+        let moved = mouse_event(EventFlags::MouseMoved, 0);
+        assert!(!moved.is_drag());
+        assert!(!moved.is_release());
+    }
+
+    #[test]
+    fn mouse_event_wheel_test(){
+        let wheel = mouse_event(EventFlags::MouseWheeled, 120 << 16);
+        assert!(wheel.is_wheel());
+        assert!(!wheel.is_press());
+        assert!(!wheel.is_drag());
+
+        let hwheel = mouse_event(EventFlags::MouseHwheeled, (-120_i32 << 16) as u32);
+        assert!(hwheel.is_wheel());
+        assert!(!hwheel.is_release());
+    }
 }
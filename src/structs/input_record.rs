@@ -2,6 +2,8 @@ use crate::structs::focus_event::FocusEventRecord;
 use crate::structs::input_event::{KeyEventRecord, MouseEventRecord};
 use crate::structs::menu_event::MenuEventRecord;
 use crate::structs::window_buffer_size_event::WindowBufferSizeRecord;
+use crate::structs::input_event::{ControlKeyState, EventFlags};
+use std::fmt::{Display, Formatter, Error};
 use winapi::um::wincon::{INPUT_RECORD, KEY_EVENT_RECORD, MOUSE_EVENT_RECORD, WINDOW_BUFFER_SIZE_RECORD, MENU_EVENT_RECORD, FOCUS_EVENT_RECORD};
 use winapi::um::wincontypes::{
     FOCUS_EVENT, KEY_EVENT, MENU_EVENT, MOUSE_EVENT, WINDOW_BUFFER_SIZE_EVENT,
@@ -29,6 +31,229 @@ pub enum InputRecord {
     MenuEvent(MenuEventRecord),
 }
 
+/// An iterator that merges the consecutive identical key down events into a single event,
+/// obtained by calling [`InputRecord::coalesce_repeats`].
+///
+/// [`InputRecord::coalesce_repeats`]: enum.InputRecord.html#method.coalesce_repeats
+#[derive(Debug, Clone)]
+pub struct CoalesceRepeats<I>{
+    // The source of the input events.
+    iter: I,
+    // The event read from the source that was not yielded yet.
+    pending: Option<InputRecord>
+}
+
+impl<I> Iterator for CoalesceRepeats<I> where I: Iterator<Item = InputRecord> {
+    type Item = InputRecord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut current = match self.pending.take() {
+            Some(record) => record,
+            None => self.iter.next()?,
+        };
+
+        if let InputRecord::KeyEvent(key) = &mut current {
+            if key.key_down {
+                for next in self.iter.by_ref() {
+                    match next {
+                        InputRecord::KeyEvent(other) if other.key_down
+                            && other.virtual_key_code == key.virtual_key_code
+                            && other.u_char == key.u_char
+                            && other.control_key_state == key.control_key_state => {
+                            key.repeat_count = key.repeat_count.saturating_add(other.repeat_count);
+                        }
+                        _ => {
+                            self.pending = Some(next);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        Some(current)
+    }
+}
+
+impl InputRecord {
+    /// Gets the [KeyEventRecord] if this is a key event, otherwise `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    ///
+    /// let keys = WinConsole::input().read_input_n(10).unwrap()
+    ///     .iter()
+    ///     .filter_map(|r| r.as_key_event().copied())
+    ///     .collect::<Vec<_>>();
+    /// ```
+    #[inline]
+    pub fn as_key_event(&self) -> Option<&KeyEventRecord> {
+        match self {
+            InputRecord::KeyEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    /// Gets the [MouseEventRecord] if this is a mouse event, otherwise `None`.
+    #[inline]
+    pub fn as_mouse_event(&self) -> Option<&MouseEventRecord> {
+        match self {
+            InputRecord::MouseEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    /// Gets the [WindowBufferSizeRecord] if this is a window buffer size event, otherwise `None`.
+    #[inline]
+    pub fn as_window_buffer_size_event(&self) -> Option<&WindowBufferSizeRecord> {
+        match self {
+            InputRecord::WindowBufferSizeEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    /// Gets the [FocusEventRecord] if this is a focus event, otherwise `None`.
+    #[inline]
+    pub fn as_focus_event(&self) -> Option<&FocusEventRecord> {
+        match self {
+            InputRecord::FocusEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    /// Gets the [MenuEventRecord] if this is a menu event, otherwise `None`.
+    #[inline]
+    pub fn as_menu_event(&self) -> Option<&MenuEventRecord> {
+        match self {
+            InputRecord::MenuEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    /// Gets an iterator that merges the consecutive key down events of the same key into a single event,
+    /// which `repeat_count` is the sum of the merged events, this is the inverse of [`KeyEventRecord::repeated`].
+    ///
+    /// The key events are merged only when the `virtual_key_code`, `u_char` and `control_key_state` are equal,
+    /// any other event is yielded as is.
+    ///
+    /// # Remarks
+    /// - To know if the next event can be merged the iterator reads it from the source, so when reading
+    ///   from the console it may block until the next event is available.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::console::WinConsole;
+    /// use win32console::structs::input_record::InputRecord;
+    ///
+    /// let records = WinConsole::input().read_available_input().unwrap();
+    /// for record in InputRecord::coalesce_repeats(records){
+    ///     println!("{}", record);
+    /// }
+    /// ```
+    ///
+    /// [`KeyEventRecord::repeated`]: ../input_event/struct.KeyEventRecord.html#method.repeated
+    pub fn coalesce_repeats<I>(records: I) -> CoalesceRepeats<I::IntoIter> where I: IntoIterator<Item = InputRecord> {
+        CoalesceRepeats{ iter: records.into_iter(), pending: None }
+    }
+
+    /// Checks if this is a key event.
+    #[inline]
+    pub fn is_key_event(&self) -> bool {
+        matches!(self, InputRecord::KeyEvent(_))
+    }
+
+    /// Checks if this is a mouse event.
+    #[inline]
+    pub fn is_mouse_event(&self) -> bool {
+        matches!(self, InputRecord::MouseEvent(_))
+    }
+
+    /// Checks if this is a window buffer size event.
+    #[inline]
+    pub fn is_window_buffer_size_event(&self) -> bool {
+        matches!(self, InputRecord::WindowBufferSizeEvent(_))
+    }
+
+    /// Checks if this is a focus event.
+    #[inline]
+    pub fn is_focus_event(&self) -> bool {
+        matches!(self, InputRecord::FocusEvent(_))
+    }
+
+    /// Checks if this is a menu event.
+    #[inline]
+    pub fn is_menu_event(&self) -> bool {
+        matches!(self, InputRecord::MenuEvent(_))
+    }
+}
+
+impl Display for InputRecord{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            InputRecord::KeyEvent(event) => {
+                let kind = if event.key_down { "KeyDown" } else { "KeyUp" };
+                write!(f, "{} {:?} (vk=0x{:02X})", kind, event.u_char, event.virtual_key_code)?;
+                write_modifiers(f, event.control_key_state)
+            }
+            InputRecord::MouseEvent(event) => {
+                write!(f, "Mouse ({},{})", event.mouse_position.x, event.mouse_position.y)?;
+
+                let buttons = event.button_state;
+                if buttons.left_button(){
+                    f.write_str(" left")?;
+                }
+                if buttons.middle_button(){
+                    f.write_str(" middle")?;
+                }
+                if buttons.right_button(){
+                    f.write_str(" right")?;
+                }
+
+                match event.event_flags {
+                    EventFlags::PressOrRelease => {},
+                    EventFlags::MouseMoved => f.write_str(" moved")?,
+                    EventFlags::DoubleClick => f.write_str(" double-click")?,
+                    EventFlags::MouseWheeled if buttons.scroll_up() => f.write_str(" wheel up")?,
+                    EventFlags::MouseWheeled => f.write_str(" wheel down")?,
+                    EventFlags::MouseHwheeled if buttons.scroll_up() => f.write_str(" wheel right")?,
+                    EventFlags::MouseHwheeled => f.write_str(" wheel left")?,
+                }
+
+                write_modifiers(f, event.control_key_state)
+            }
+            InputRecord::WindowBufferSizeEvent(event) => {
+                write!(f, "Resize ({},{})", event.size.x, event.size.y)
+            }
+            InputRecord::FocusEvent(event) => {
+                f.write_str(if event.set_focus { "Focus gained" } else { "Focus lost" })
+            }
+            InputRecord::MenuEvent(event) => {
+                write!(f, "Menu {}", event.command_id)
+            }
+        }
+    }
+}
+
+/// Writes the pressed modifier keys of the given state as ` Ctrl+Alt+Shift`, writes nothing if
+/// no modifier is pressed.
+fn write_modifiers(f: &mut Formatter<'_>, state: ControlKeyState) -> Result<(), Error>{
+    let modifiers = [
+        (state.is_ctrl_pressed(), "Ctrl"),
+        (state.is_alt_pressed(), "Alt"),
+        (state.is_shift_pressed(), "Shift")
+    ];
+
+    let mut separator = " ";
+    for (_, name) in modifiers.iter().filter(|(pressed, _)| *pressed) {
+        f.write_str(separator)?;
+        f.write_str(name)?;
+        separator = "+";
+    }
+
+    Ok(())
+}
+
 impl From<INPUT_RECORD> for InputRecord {
     fn from(record: INPUT_RECORD) -> Self {
         match record.EventType {
@@ -121,7 +346,8 @@ impl Into<INPUT_RECORD> for FocusEventRecord{
 #[cfg(test)]
 mod tests{
     use super::*;
-    use crate::structs::input_event::ControlKeyState;
+    use crate::structs::coord::Coord;
+    use crate::structs::input_event::ButtonState;
 
     #[test]
     fn key_event_into_input_record_test(){
@@ -144,4 +370,121 @@ mod tests{
         assert_eq!(key_event.u_char, from_record.u_char);
         assert_eq!(key_event.control_key_state, from_record.control_key_state);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn as_event_test(){
+        let key_event : KeyEventRecord = unsafe { std::mem::zeroed() };
+        let mouse_event : MouseEventRecord = unsafe { std::mem::zeroed() };
+        let size_event = WindowBufferSizeRecord{ size: Coord::new(80, 25) };
+        let focus_event = FocusEventRecord{ set_focus: true };
+        let menu_event = MenuEventRecord{ command_id: 1 };
+
+        assert_eq!(Some(&key_event), InputRecord::KeyEvent(key_event).as_key_event());
+        assert_eq!(Some(&mouse_event), InputRecord::MouseEvent(mouse_event).as_mouse_event());
+        assert_eq!(Some(&size_event), InputRecord::WindowBufferSizeEvent(size_event).as_window_buffer_size_event());
+        assert_eq!(Some(&focus_event), InputRecord::FocusEvent(focus_event).as_focus_event());
+        assert_eq!(Some(&menu_event), InputRecord::MenuEvent(menu_event).as_menu_event());
+
+        assert_eq!(None, InputRecord::FocusEvent(focus_event).as_key_event());
+        assert_eq!(None, InputRecord::KeyEvent(key_event).as_mouse_event());
+        assert_eq!(None, InputRecord::KeyEvent(key_event).as_window_buffer_size_event());
+        assert_eq!(None, InputRecord::MenuEvent(menu_event).as_focus_event());
+        assert_eq!(None, InputRecord::MouseEvent(mouse_event).as_menu_event());
+    }
+
+    #[test]
+    fn is_event_test(){
+        let key_record = InputRecord::KeyEvent(unsafe { std::mem::zeroed() });
+        let mouse_record = InputRecord::MouseEvent(unsafe { std::mem::zeroed() });
+        let size_record = InputRecord::WindowBufferSizeEvent(WindowBufferSizeRecord{ size: Coord::new(80, 25) });
+        let focus_record = InputRecord::FocusEvent(FocusEventRecord{ set_focus: false });
+        let menu_record = InputRecord::MenuEvent(MenuEventRecord{ command_id: 1 });
+
+        assert!(key_record.is_key_event() && !key_record.is_mouse_event());
+        assert!(mouse_record.is_mouse_event() && !mouse_record.is_key_event());
+        assert!(size_record.is_window_buffer_size_event() && !size_record.is_focus_event());
+        assert!(focus_record.is_focus_event() && !focus_record.is_menu_event());
+        assert!(menu_record.is_menu_event() && !menu_record.is_window_buffer_size_event());
+    }
+
+    #[test]
+    fn display_key_event_test(){
+        let mut key_event : KeyEventRecord = unsafe { std::mem::zeroed() };
+        key_event.key_down = true;
+        key_event.u_char = 'a';
+        key_event.virtual_key_code = 0x41;
+        key_event.repeat_count = 1;
+
+        assert_eq!("KeyDown 'a' (vk=0x41)", InputRecord::KeyEvent(key_event).to_string());
+
+        key_event.key_down = false;
+        key_event.control_key_state = ControlKeyState::none().with_ctrl().with_shift();
+        assert_eq!("KeyUp 'a' (vk=0x41) Ctrl+Shift", InputRecord::KeyEvent(key_event).to_string());
+    }
+
+    #[test]
+    fn display_mouse_event_test(){
+        let mut mouse_event : MouseEventRecord = unsafe { std::mem::zeroed() };
+        mouse_event.mouse_position = Coord::new(10, 5);
+        mouse_event.button_state = ButtonState::from(0x1_u32);
+        mouse_event.control_key_state = ControlKeyState::none();
+        mouse_event.event_flags = EventFlags::PressOrRelease;
+
+        assert_eq!("Mouse (10,5) left", InputRecord::MouseEvent(mouse_event).to_string());
+    }
+
+    #[test]
+    fn display_other_events_test(){
+        assert_eq!("Resize (80,25)", InputRecord::WindowBufferSizeEvent(WindowBufferSizeRecord{ size: Coord::new(80, 25) }).to_string());
+        assert_eq!("Focus gained", InputRecord::FocusEvent(FocusEventRecord{ set_focus: true }).to_string());
+        assert_eq!("Menu 3", InputRecord::MenuEvent(MenuEventRecord{ command_id: 3 }).to_string());
+    }
+
+    #[test]
+    fn coalesce_repeats_test(){
+        let mut key_event : KeyEventRecord = unsafe { std::mem::zeroed() };
+        key_event.key_down = true;
+        key_event.u_char = 'a';
+        key_event.virtual_key_code = 0x41;
+        key_event.repeat_count = 1;
+
+        let mut other_key = key_event;
+        other_key.u_char = 'b';
+        other_key.virtual_key_code = 0x42;
+
+        let records = vec![
+            InputRecord::KeyEvent(key_event),
+            InputRecord::KeyEvent(KeyEventRecord{ repeat_count: 2, ..key_event }),
+            InputRecord::KeyEvent(key_event),
+            InputRecord::KeyEvent(other_key),
+            InputRecord::KeyEvent(other_key),
+        ];
+
+        let coalesced = InputRecord::coalesce_repeats(records).collect::<Vec<_>>();
+        assert_eq!(vec![
+            InputRecord::KeyEvent(KeyEventRecord{ repeat_count: 4, ..key_event }),
+            InputRecord::KeyEvent(KeyEventRecord{ repeat_count: 2, ..other_key }),
+        ], coalesced);
+    }
+
+    #[test]
+    fn coalesce_repeats_different_modifiers_test(){
+        let mut key_event : KeyEventRecord = unsafe { std::mem::zeroed() };
+        key_event.key_down = true;
+        key_event.u_char = 'a';
+        key_event.repeat_count = 1;
+
+        let mut key_up = key_event;
+        key_up.key_down = false;
+
+        let records = vec![
+            InputRecord::KeyEvent(key_event),
+            InputRecord::KeyEvent(KeyEventRecord{ control_key_state: ControlKeyState::none().with_shift(), ..key_event }),
+            InputRecord::KeyEvent(key_up),
+            InputRecord::KeyEvent(key_up),
+            InputRecord::FocusEvent(FocusEventRecord{ set_focus: true }),
+        ];
+
+        assert_eq!(records, InputRecord::coalesce_repeats(records.clone()).collect::<Vec<_>>());
+    }
+}
@@ -16,3 +16,4 @@ pub mod menu_event;
 pub mod window_buffer_size_event;
 pub mod char_info;
 pub mod console_read_control;
+pub mod code_page;
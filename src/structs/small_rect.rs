@@ -2,11 +2,13 @@ use std::fmt::{Display, Formatter, Error};
 use std::ops::Div;
 use std::ops::Mul;
 use winapi::um::wincon::SMALL_RECT;
+use crate::structs::coord::Coord;
 
 /// Represents a `SMALL_RECT` which defines the coordinates of the upper left and lower right corners of a rectangle.
 ///
 /// link: `https://docs.microsoft.com/en-us/windows/console/small-rect-str`
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SmallRect {
     pub left: i16,
     pub top: i16,
@@ -15,6 +17,9 @@ pub struct SmallRect {
 }
 
 impl SmallRect {
+    /// An empty `SmallRect` with no width and height.
+    pub const EMPTY: SmallRect = SmallRect{ left: 0, top: 0, right: -1, bottom: -1 };
+
     /// Creates a new `SmallRect`.
     #[inline]
     pub fn new(left: i16, top: i16, right: i16, bottom: i16) -> Self{
@@ -64,6 +69,89 @@ impl SmallRect {
             bottom
         }
     }
+
+    /// Gets the upper left corner of this `SmallRect`.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::small_rect::SmallRect;
+    /// use win32console::structs::coord::Coord;
+    /// assert_eq!(Coord::new(2, 3), SmallRect::new(2, 3, 10, 20).top_left());
+    /// ```
+    #[inline]
+    pub fn top_left(&self) -> Coord{
+        Coord::new(self.left, self.top)
+    }
+
+    /// Gets the lower right corner of this `SmallRect`.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::small_rect::SmallRect;
+    /// use win32console::structs::coord::Coord;
+    /// assert_eq!(Coord::new(10, 20), SmallRect::new(2, 3, 10, 20).bottom_right());
+    /// ```
+    #[inline]
+    pub fn bottom_right(&self) -> Coord{
+        Coord::new(self.right, self.bottom)
+    }
+
+    /// Gets the width of this `SmallRect`, where `left` and `right` are inclusive.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::small_rect::SmallRect;
+    /// assert_eq!(80, SmallRect::new(0, 0, 79, 24).width());
+    /// ```
+    #[inline]
+    pub fn width(&self) -> i16{
+        self.right - self.left + 1
+    }
+
+    /// Gets the height of this `SmallRect`, where `top` and `bottom` are inclusive.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::small_rect::SmallRect;
+    /// assert_eq!(25, SmallRect::new(0, 0, 79, 24).height());
+    /// ```
+    #[inline]
+    pub fn height(&self) -> i16{
+        self.bottom - self.top + 1
+    }
+
+    /// Checks if this `SmallRect` is empty, that is when its width or height is zero or negative.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::small_rect::SmallRect;
+    ///
+    /// assert!(SmallRect::EMPTY.is_empty());
+    /// assert!(!SmallRect::new(0, 0, 0, 0).is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool{
+        self.right < self.left || self.bottom < self.top
+    }
+
+    /// Gets this `SmallRect` with each of its edges clamped to the edges of the given bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use win32console::structs::small_rect::SmallRect;
+    ///
+    /// let bounds = SmallRect::new(0, 0, 79, 24);
+    /// assert_eq!(SmallRect::new(0, 10, 79, 24), SmallRect::new(-5, 10, 100, 30).clamp_to(bounds));
+    /// ```
+    #[inline]
+    pub fn clamp_to(&self, bounds: SmallRect) -> SmallRect{
+        SmallRect{
+            left: self.left.max(bounds.left).min(bounds.right),
+            top: self.top.max(bounds.top).min(bounds.bottom),
+            right: self.right.max(bounds.left).min(bounds.right),
+            bottom: self.bottom.max(bounds.top).min(bounds.bottom)
+        }
+    }
 }
 
 impl Display for SmallRect{
@@ -73,6 +161,14 @@ impl Display for SmallRect{
     }
 }
 
+impl From<(i16, i16, i16, i16)> for SmallRect {
+    /// Creates a `SmallRect` from a `(left, top, right, bottom)` tuple.
+    #[inline]
+    fn from((left, top, right, bottom): (i16, i16, i16, i16)) -> Self {
+        SmallRect::new(left, top, right, bottom)
+    }
+}
+
 impl From<SMALL_RECT> for SmallRect {
     #[inline]
     fn from(rect: SMALL_RECT) -> Self {
@@ -140,4 +236,52 @@ mod tests{
         let a = SmallRect::new(2, 4, 6, 8);
         assert_eq!(SmallRect::new(1, 2, 3, 4), a / 2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn small_rect_width_height_test(){
+        let a = SmallRect::new(2, 3, 11, 7);
+        assert_eq!(10, a.width());
+        assert_eq!(5, a.height());
+    }
+
+    #[test]
+    fn small_rect_clamp_to_negative_test(){
+        let bounds = SmallRect::new(0, 0, 79, 24);
+        let rect = SmallRect::new(-10, -2, 5, 5);
+        assert_eq!(SmallRect::new(0, 0, 5, 5), rect.clamp_to(bounds));
+    }
+
+    #[test]
+    fn small_rect_clamp_to_out_of_range_test(){
+        let bounds = SmallRect::new(0, 0, 79, 24);
+        let rect = SmallRect::new(70, 20, 120, 40);
+        assert_eq!(SmallRect::new(70, 20, 79, 24), rect.clamp_to(bounds));
+    }
+
+    #[test]
+    fn small_rect_from_tuple_test(){
+        assert_eq!(SmallRect::new(1, 2, 3, 4), SmallRect::from((1, 2, 3, 4)));
+    }
+
+    #[test]
+    fn small_rect_corners_test(){
+        let rect = SmallRect::from((5, 6, 40, 12));
+        assert_eq!(Coord::new(5, 6), rect.top_left());
+        assert_eq!(Coord::new(40, 12), rect.bottom_right());
+
+        let (top_left, bottom_right) = (rect.top_left(), rect.bottom_right());
+        assert_eq!(rect, SmallRect::from((top_left.x, top_left.y, bottom_right.x, bottom_right.y)));
+    }
+
+    #[test]
+    fn small_rect_is_empty_test(){
+        assert!(SmallRect::EMPTY.is_empty());
+        assert_eq!(0, SmallRect::EMPTY.width());
+        assert_eq!(0, SmallRect::EMPTY.height());
+
+        assert!(SmallRect::new(5, 5, 4, 10).is_empty());
+        assert!(SmallRect::new(5, 5, 10, 2).is_empty());
+        assert!(!SmallRect::new(5, 5, 5, 5).is_empty());
+        assert!(!SmallRect::new(0, 0, 79, 24).is_empty());
+    }
+}
@@ -0,0 +1,79 @@
+use std::fs::File;
+use std::io::Write;
+use std::os::windows::io::AsRawHandle;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+use crate::console::WinConsole;
+use crate::structs::handle::Handle;
+use crate::structs::input_event::KeyEventRecord;
+use crate::structs::input_record::InputRecord;
+
+// All the tests share the same console, tests that change its state must hold this lock.
+static CONSOLE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Locks the console until the returned guard is dropped,
+/// so the tests that change the console state don't run concurrently.
+pub(crate) fn lock_console() -> MutexGuard<'static, ()>{
+    // A failed test poisons the lock, but the other tests still can use the console
+    CONSOLE_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Creates a key down event for each char, the virtual key code is the uppercase char
+/// which also matches the control keys as `\r` (`VK_RETURN`) or `\x08` (`VK_BACK`).
+pub(crate) fn key_events(chars: &str) -> Vec<InputRecord>{
+    let mut key_event : KeyEventRecord = unsafe { std::mem::zeroed() };
+    key_event.repeat_count = 1;
+    key_event.key_down = true;
+
+    chars.chars()
+        .map(|c| {
+            key_event.u_char = c;
+            key_event.virtual_key_code = c.to_ascii_uppercase() as u16;
+            InputRecord::KeyEvent(key_event)
+        })
+        .collect()
+}
+
+/// A file in the temporary directory used as a redirected console handle,
+/// the file is deleted when dropped.
+pub(crate) struct RedirectedFile{
+    path: PathBuf,
+    file: Option<File>
+}
+
+impl RedirectedFile{
+    /// Creates an empty file opened for writing.
+    pub(crate) fn create(name: &str) -> Self{
+        let path = std::env::temp_dir().join(name);
+        let file = File::create(&path).unwrap();
+        RedirectedFile{ path, file: Some(file) }
+    }
+
+    /// Creates a file with the given contents opened for reading.
+    pub(crate) fn with_contents(name: &str, contents: &[u8]) -> Self{
+        let path = std::env::temp_dir().join(name);
+        File::create(&path).unwrap().write_all(contents).unwrap();
+
+        let file = File::open(&path).unwrap();
+        RedirectedFile{ path, file: Some(file) }
+    }
+
+    /// Gets a `WinConsole` that uses the file handle.
+    pub(crate) fn console(&self) -> WinConsole{
+        let file = self.file.as_ref().unwrap();
+        WinConsole::with_handle(Handle::new(file.as_raw_handle() as _))
+    }
+
+    /// Reads the current contents of the file.
+    pub(crate) fn contents(&self) -> Vec<u8>{
+        std::fs::read(&self.path).unwrap()
+    }
+}
+
+impl Drop for RedirectedFile{
+    fn drop(&mut self) {
+        // The file must be closed before it can be deleted
+        drop(self.file.take());
+        let _ = std::fs::remove_file(&self.path);
+    }
+}